@@ -1,15 +1,28 @@
 //! Implementation of the attribute macros.
 
 use convert_case::{Case, Casing};
-use proc_macro2::{Span, TokenStream};
-use quote::quote;
-use syn::{Error, Ident, Item};
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    punctuated::Punctuated,
+    Attribute, Error, FnArg, ForeignItem, GenericParam, Ident, ImplItem, Item, ItemFn,
+    ItemForeignMod, ItemImpl, ItemMod, ItemTrait, Meta, MetaNameValue, Pat, PatIdent, ReturnType,
+    Token, TraitBound, TraitItem, Type, TypeParamBound, Visibility, WhereClause, WherePredicate,
+};
 
 #[cfg(feature = "customise")]
-use crate::config::attr::AttrCustomisations;
+use crate::config::{
+    assoc_items::{ImplBlockCustomisations, TraitDefCustomisations},
+    attr::AttrCustomisations,
+    customise_core::get_customisations_from_attrs,
+    module::ModuleCustomisations,
+};
 use crate::{
-    config::attr::AttrConfig,
-    util::{get_docs, get_vis_name_attrs},
+    config::{assoc_items::AssocItemsConfig, attr::AttrConfig, module::ModuleConfig},
+    util::{crate_module_path, docs_map_tokens, get_docs, get_name, get_vis_name_attrs},
 };
 
 pub fn docs_const_impl(
@@ -21,6 +34,14 @@ pub fn docs_const_impl(
     #[cfg(feature = "customise")]
     let config = AttrConfig::default().with_customisations(customisations);
 
+    if let Item::ForeignMod(ref block) = item {
+        let consts = foreign_docs_consts(block, config)?;
+        return Ok(quote! {
+            #item
+            #(#consts)*
+        });
+    }
+
     let (item_vis, item_name, attrs) = get_vis_name_attrs(&item)?;
 
     let docs = match (get_docs(attrs, config.trim)?, config.default_value) {
@@ -40,3 +61,670 @@ pub fn docs_const_impl(
         #const_vis const #const_ident: &'static str = #docs;
     })
 }
+
+/// Generate a docs constant for each function, static and type in an `extern`
+/// block, applying the config to every one of them.
+fn foreign_docs_consts(
+    block: &ItemForeignMod,
+    config: AttrConfig,
+) -> syn::Result<Vec<TokenStream>> {
+    if config.custom_name.is_some() {
+        Err(Error::new_spanned(
+            &block.abi,
+            "Cannot rename the constants of an extern block, since there is one per item",
+        ))?
+    }
+
+    let mut consts = vec![];
+    for foreign_item in block.items.iter() {
+        let (item_vis, item_ident, attrs) = match foreign_item {
+            ForeignItem::Fn(item) => (&item.vis, &item.sig.ident, &item.attrs),
+            ForeignItem::Static(item) => (&item.vis, &item.ident, &item.attrs),
+            ForeignItem::Type(item) => (&item.vis, &item.ident, &item.attrs),
+            _ => continue,
+        };
+
+        let docs = match (get_docs(attrs, config.trim)?, &config.default_value) {
+            (Some(docs), _) => quote! { #docs },
+            (None, Some(default)) => quote! { #default },
+            (None, None) => Err(Error::new_spanned(item_ident, "Missing doc comments"))?,
+        };
+
+        let const_vis = config.custom_vis.as_ref().unwrap_or(item_vis);
+        let const_ident = format_ident!(
+            "{}_DOCS",
+            item_ident.unraw().to_string().to_case(Case::UpperSnake)
+        );
+        consts.push(quote! {
+            #const_vis const #const_ident: &'static str = #docs;
+        });
+    }
+    Ok(consts)
+}
+
+pub fn param_docs_impl(
+    mut item: ItemFn,
+    #[cfg(feature = "customise")] customisations: AttrCustomisations,
+) -> syn::Result<TokenStream> {
+    #[cfg(not(feature = "customise"))]
+    let config = AttrConfig::default();
+    #[cfg(feature = "customise")]
+    let config = AttrConfig::default().with_customisations(customisations);
+
+    // docs from a `# Arguments` section of the function's own docs
+    let mut section_docs = get_docs(&item.attrs, config.trim)?
+        .and_then(|docs| docs.as_known_str().map(parse_arguments_section))
+        .unwrap_or_default();
+
+    let mut entries = vec![];
+    for input in item.sig.inputs.iter_mut() {
+        let (pat, attrs) = match input {
+            FnArg::Receiver(receiver) => (None, &mut receiver.attrs),
+            FnArg::Typed(pat_type) => (Some(&*pat_type.pat), &mut pat_type.attrs),
+        };
+
+        // `#[param_doc = "..."]` is processed just like `#[doc = "..."]`
+        let (param_doc_attrs, other_attrs) = attrs
+            .drain(..)
+            .partition::<Vec<_>, _>(|attr| attr.path().is_ident("param_doc"));
+        *attrs = other_attrs;
+        let param_doc_attrs = param_doc_attrs
+            .into_iter()
+            .map(|attr| match attr.meta {
+                Meta::NameValue(MetaNameValue { value, .. }) => {
+                    Ok(parse_quote! { #[doc = #value] })
+                }
+                other => Err(Error::new_spanned(
+                    other,
+                    "param_doc is not name-value. Expecting `param_doc = \"...\"`",
+                )),
+            })
+            .collect::<syn::Result<Vec<Attribute>>>()?;
+
+        let ident = match pat {
+            Some(Pat::Ident(PatIdent { ident, .. })) => ident.unraw(),
+            _ if param_doc_attrs.is_empty() => continue,
+            _ => Err(Error::new_spanned(
+                &*input,
+                "Only parameters with identifier patterns can be documented",
+            ))?,
+        };
+        let name = ident.to_string();
+
+        let section_doc = section_docs
+            .iter()
+            .position(|(section_name, _)| *section_name == name)
+            .map(|i| section_docs.remove(i).1);
+        let docs = match (get_docs(&param_doc_attrs, config.trim)?, section_doc) {
+            (Some(docs), _) => quote! { #docs },
+            (None, Some(docs)) => quote! { #docs },
+            (None, None) => match config.default_value {
+                Some(ref default) => quote! { #default },
+                None => continue,
+            },
+        };
+        entries.push((name, docs));
+    }
+
+    if let Some((name, _)) = section_docs.first() {
+        Err(Error::new_spanned(
+            &item.sig.ident,
+            format!("Parameter `{name}` documented in `# Arguments` does not exist"),
+        ))?
+    }
+
+    let const_vis = config.custom_vis.unwrap_or_else(|| item.vis.clone());
+    let const_name = config.custom_name.unwrap_or_else(|| {
+        let fn_name = item.sig.ident.unraw().to_string();
+        format!("{}_PARAM_DOCS", fn_name.to_case(Case::UpperSnake))
+    });
+    let const_ident = Ident::new(&const_name, Span::call_site());
+    let documented_module_path = crate_module_path();
+    let docs_map = docs_map_tokens(&entries);
+
+    Ok(quote! {
+        #item
+        #const_vis const #const_ident: #documented_module_path::DocsMap = #docs_map;
+    })
+}
+
+/// Parse the list in the `# Arguments` section of some docs, following the
+/// conventions of the standard library, e.g.:
+///
+/// ```markdown
+/// # Arguments
+///
+/// * `name` - The description of the parameter,
+///   which may span multiple lines.
+/// ```
+///
+/// Returns the name and description of each listed parameter.
+fn parse_arguments_section(docs: &str) -> Vec<(String, String)> {
+    let mut lines = docs
+        .lines()
+        .skip_while(|line| {
+            let heading = line.trim_start().trim_start_matches('#');
+            !(line.trim_start().starts_with('#')
+                && ["arguments", "parameters"].contains(&heading.trim().to_lowercase().as_str()))
+        })
+        .skip(1)
+        .take_while(|line| !line.trim_start().starts_with('#'));
+
+    let mut params = vec![];
+    let mut current: Option<(String, String)> = None;
+    for line in lines.by_ref() {
+        let trimmed = line.trim();
+        let item = trimmed
+            .strip_prefix("* ")
+            .or_else(|| trimmed.strip_prefix("- "))
+            .and_then(|item| {
+                let item = item.trim_start();
+                let (name, rest) = match item.strip_prefix('`') {
+                    Some(item) => item.split_once('`')?,
+                    None => item.split_once(|c: char| c.is_whitespace() || c == ':')?,
+                };
+                let description = rest
+                    .trim_start()
+                    .trim_start_matches(['-', ':', '–', '—'])
+                    .trim_start();
+                Some((name.trim().to_string(), description.to_string()))
+            });
+        match item {
+            Some(item) => params.extend(current.replace(item)),
+            None if trimmed.is_empty() => params.extend(current.take()),
+            None => {
+                // continuation of the previous item
+                if let Some((_, description)) = current.as_mut() {
+                    description.push('\n');
+                    description.push_str(trimmed);
+                }
+            }
+        }
+    }
+    params.extend(current);
+    params
+}
+
+pub fn documented_impl_impl(
+    mut item: ItemImpl,
+    #[cfg(feature = "customise")] customisations: ImplBlockCustomisations,
+) -> syn::Result<TokenStream> {
+    #[cfg(not(feature = "customise"))]
+    let base_config = AssocItemsConfig::default();
+    #[cfg(feature = "customise")]
+    let base_config = AssocItemsConfig::default().with_impl_block_customisations(customisations);
+
+    let map_ident = base_config
+        .map_name
+        .clone()
+        .unwrap_or_else(|| format_ident!("METHOD_DOCS"));
+
+    let mut consts = vec![];
+    let mut entries = vec![];
+    let mut const_names = vec![map_ident.to_string()];
+    for impl_item in item.items.iter_mut() {
+        let (item_vis, item_ident, attrs) = match impl_item {
+            ImplItem::Const(item) => (&item.vis, &item.ident, &mut item.attrs),
+            ImplItem::Fn(item) => (&item.vis, &item.sig.ident, &mut item.attrs),
+            ImplItem::Type(item) => (&item.vis, &item.ident, &mut item.attrs),
+            _ => continue,
+        };
+        let (name, docs) = get_assoc_item_docs(item_ident, attrs, &base_config, "documented_impl")?;
+
+        let const_ident = format_ident!(
+            "{}_DOCS",
+            item_ident.unraw().to_string().to_case(Case::UpperSnake)
+        );
+        if const_names.contains(&const_ident.to_string()) {
+            Err(Error::new_spanned(
+                item_ident,
+                format!("Multiple items would generate the constant `{const_ident}`"),
+            ))?
+        }
+        const_names.push(const_ident.to_string());
+        consts.push(quote! {
+            #item_vis const #const_ident: &'static str = #docs;
+        });
+        entries.push((name, quote! { Self::#const_ident }));
+    }
+
+    let map_vis = base_config.custom_vis;
+    let docs_map = docs_map_tokens(&entries);
+
+    let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+    let self_ty = &item.self_ty;
+    let documented_module_path = crate_module_path();
+    Ok(quote! {
+        #item
+
+        impl #impl_generics #self_ty #where_clause {
+            #(#consts)*
+
+            #map_vis const #map_ident: #documented_module_path::DocsMap = #docs_map;
+        }
+    })
+}
+
+pub fn documented_trait_impl(
+    mut item: ItemTrait,
+    #[cfg(feature = "customise")] customisations: TraitDefCustomisations,
+) -> syn::Result<TokenStream> {
+    #[cfg(not(feature = "customise"))]
+    let base_config = AssocItemsConfig::default();
+    #[cfg(feature = "customise")]
+    let base_config = AssocItemsConfig::default().with_trait_def_customisations(customisations);
+
+    let mut entries = vec![];
+    for trait_item in item.items.iter_mut() {
+        let (item_ident, attrs) = match trait_item {
+            TraitItem::Const(item) => (&item.ident, &mut item.attrs),
+            TraitItem::Fn(item) => (&item.sig.ident, &mut item.attrs),
+            TraitItem::Type(item) => (&item.ident, &mut item.attrs),
+            _ => continue,
+        };
+        entries.push(get_assoc_item_docs(
+            item_ident,
+            attrs,
+            &base_config,
+            "documented_trait",
+        )?);
+    }
+
+    let documented_module_path = crate_module_path();
+    let ident = &item.ident;
+
+    let dyn_impl = if base_config
+        .dyn_impl
+        .unwrap_or_else(|| is_dyn_compatible(&item))
+    {
+        let docs = match (
+            get_docs(&item.attrs, base_config.trim)?,
+            &base_config.default_value,
+        ) {
+            (Some(docs), _) => quote! { #docs },
+            (None, Some(default)) => quote! { #default },
+            (None, None) => Err(Error::new_spanned(ident, "Missing doc comments"))?,
+        };
+        let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #documented_module_path::Documented for dyn #ident #ty_generics
+            #where_clause
+            {
+                const DOCS: &'static str = #docs;
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let map_vis = base_config.custom_vis.unwrap_or_else(|| item.vis.clone());
+    let map_ident = format_ident!(
+        "{}_ITEM_DOCS",
+        ident.unraw().to_string().to_case(Case::UpperSnake)
+    );
+    let docs_map = docs_map_tokens(&entries);
+
+    Ok(quote! {
+        #item
+
+        #dyn_impl
+
+        #map_vis const #map_ident: #documented_module_path::DocsMap = #docs_map;
+    })
+}
+
+/// Conservatively determine whether `dyn Trait` is a valid type.
+///
+/// This errs on the side of `false`, since the `dyn Trait` impl can always be
+/// forced using `dyn_impl = true`.
+fn is_dyn_compatible(item: &ItemTrait) -> bool {
+    fn is_maybe_bound(bound: &TraitBound) -> bool {
+        matches!(
+            bound.to_token_stream().into_iter().next(),
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '?'
+        )
+    }
+    fn is_sized_bound(bound: &TypeParamBound) -> bool {
+        matches!(
+            bound,
+            TypeParamBound::Trait(bound)
+                if !is_maybe_bound(bound)
+                    && bound.path.segments.last().is_some_and(|seg| seg.ident == "Sized")
+        )
+    }
+    // only auto traits are known to keep the trait dyn-compatible
+    fn is_known_dyn_compatible_bound(bound: &TypeParamBound) -> bool {
+        const AUTO_TRAITS: [&str; 5] = ["Send", "Sync", "Unpin", "UnwindSafe", "RefUnwindSafe"];
+        match bound {
+            TypeParamBound::Lifetime(_) => true,
+            TypeParamBound::Trait(bound) => {
+                is_maybe_bound(bound)
+                    || bound.path.segments.last().is_some_and(|seg| {
+                        seg.arguments.is_empty() && AUTO_TRAITS.iter().any(|name| seg.ident == name)
+                    })
+            }
+            _ => false,
+        }
+    }
+    fn is_self(ty: &Type) -> bool {
+        matches!(ty, Type::Path(ty) if ty.qself.is_none() && ty.path.is_ident("Self"))
+    }
+    fn self_bounds(where_clause: Option<&WhereClause>) -> impl Iterator<Item = &TypeParamBound> {
+        where_clause
+            .into_iter()
+            .flat_map(|clause| clause.predicates.iter())
+            .filter_map(|predicate| match predicate {
+                WherePredicate::Type(predicate) if is_self(&predicate.bounded_ty) => {
+                    Some(predicate.bounds.iter())
+                }
+                _ => None,
+            })
+            .flatten()
+    }
+    fn requires_self_sized(where_clause: Option<&WhereClause>) -> bool {
+        self_bounds(where_clause).any(is_sized_bound)
+    }
+    fn mentions_self(tokens: TokenStream) -> bool {
+        tokens.into_iter().any(|tt| match tt {
+            TokenTree::Ident(ident) => ident == "Self",
+            TokenTree::Group(group) => mentions_self(group.stream()),
+            _ => false,
+        })
+    }
+
+    if !item
+        .supertraits
+        .iter()
+        .chain(self_bounds(item.generics.where_clause.as_ref()))
+        .all(is_known_dyn_compatible_bound)
+    {
+        return false;
+    }
+    item.items.iter().all(|trait_item| match trait_item {
+        TraitItem::Fn(item) => {
+            let sig = &item.sig;
+            if requires_self_sized(sig.generics.where_clause.as_ref()) {
+                return true;
+            }
+            let has_type_params = sig
+                .generics
+                .params
+                .iter()
+                .any(|param| !matches!(param, GenericParam::Lifetime(_)));
+            let has_impl_args = sig.inputs.iter().any(
+                |arg| matches!(arg, FnArg::Typed(arg) if matches!(*arg.ty, Type::ImplTrait(_))),
+            );
+            let has_self_args = sig.inputs.iter().any(
+                |arg| matches!(arg, FnArg::Typed(arg) if mentions_self(arg.ty.to_token_stream())),
+            );
+            let bad_return = match &sig.output {
+                ReturnType::Default => false,
+                ReturnType::Type(_, ty) => {
+                    matches!(**ty, Type::ImplTrait(_)) || mentions_self(ty.to_token_stream())
+                }
+            };
+            sig.receiver().is_some()
+                && sig.asyncness.is_none()
+                && !has_type_params
+                && !has_impl_args
+                && !has_self_args
+                && !bad_return
+        }
+        // associated types would have to be specified in `dyn Trait<Type = ..>`
+        TraitItem::Const(_) | TraitItem::Type(_) | TraitItem::Macro(_) => false,
+        _ => true,
+    })
+}
+
+/// Get the name and docs of an associated item, applying and stripping its
+/// helper attributes.
+#[cfg_attr(not(feature = "customise"), allow(clippy::ptr_arg))]
+fn get_assoc_item_docs(
+    ident: &Ident,
+    attrs: &mut Vec<Attribute>,
+    base_config: &AssocItemsConfig,
+    #[allow(unused_variables)] helper_attr: &str,
+) -> syn::Result<(String, TokenStream)> {
+    #[cfg(not(feature = "customise"))]
+    let config = base_config.clone();
+    #[cfg(feature = "customise")]
+    let config = get_customisations_from_attrs(attrs, helper_attr)
+        .map(|c| base_config.with_item_customisations(c))?;
+    // attribute macros cannot declare helper attributes, so strip them
+    #[cfg(feature = "customise")]
+    attrs.retain(|attr| !attr.path().is_ident(helper_attr));
+
+    let docs = match (get_docs(attrs, config.trim)?, config.default_value) {
+        (Some(docs), _) => quote! { #docs },
+        (None, Some(default)) => quote! { #default },
+        (None, None) => Err(Error::new_spanned(ident, "Missing doc comments"))?,
+    };
+    let name = get_name(Some(&ident.unraw()), config.rename_mode)
+        .expect("Associated item should have an ident");
+
+    Ok((name, docs))
+}
+
+pub fn documented_module_impl(
+    mut item: ItemMod,
+    #[cfg(feature = "customise")] customisations: ModuleCustomisations,
+) -> syn::Result<TokenStream> {
+    #[cfg(not(feature = "customise"))]
+    let config = ModuleConfig::default();
+    #[cfg(feature = "customise")]
+    let config = ModuleConfig::default().with_customisations(customisations);
+
+    let Some((_, ref mut items)) = item.content else {
+        Err(Error::new_spanned(
+            &item,
+            "Only inline modules are supported, i.e. `mod name { ... }`",
+        ))?
+    };
+
+    let mut entries = vec![];
+    collect_module_entries(items, "", &config, &mut entries)?;
+    let docs_map = docs_map_tokens(&entries);
+
+    let const_vis = config.custom_vis;
+    let documented_module_path = crate_module_path();
+    items.push(parse_quote! {
+        #const_vis const MODULE_DOCS: #documented_module_path::DocsMap = #docs_map;
+    });
+
+    Ok(quote! { #item })
+}
+
+/// Collect the names and docs of all documented items in a module, descending
+/// into nested inline modules if configured to.
+fn collect_module_entries(
+    items: &[Item],
+    prefix: &str,
+    config: &ModuleConfig,
+    entries: &mut Vec<(String, TokenStream)>,
+) -> syn::Result<()> {
+    for item in items {
+        // items that cannot have docs (e.g. `impl` blocks) are simply skipped
+        let Ok((item_vis, item_name, attrs)) = get_vis_name_attrs(item) else {
+            continue;
+        };
+        if config.skip_private && matches!(item_vis, Visibility::Inherited) {
+            continue;
+        }
+        let name = format!("{prefix}{}", item_name.trim_start_matches("r#"));
+
+        let docs = match (get_docs(attrs, config.trim)?, &config.default_value) {
+            (Some(docs), _) => Some(quote! { #docs }),
+            (None, Some(default)) => Some(quote! { #default }),
+            (None, None) => None,
+        };
+        if let Some(docs) = docs {
+            if entries.iter().any(|(existing, _)| *existing == name) {
+                Err(Error::new_spanned(
+                    item,
+                    format!("Multiple items named `{name}` in this module"),
+                ))?
+            }
+            entries.push((name.clone(), docs));
+        }
+
+        if let Item::Mod(ItemMod { content: Some((_, ref items)), .. }) = item {
+            if config.recursive {
+                collect_module_entries(items, &format!("{name}::"), config, entries)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The kinds of derivable traits of this crate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DerivableKind {
+    Documented,
+    Fields,
+    Variants,
+}
+impl DerivableKind {
+    fn from_name(name: &str) -> Option<(Self, bool)> {
+        let (name, is_opt) = match name.strip_suffix("Opt") {
+            Some(name) => (name, true),
+            None => (name, false),
+        };
+        let kind = match name {
+            "Documented" => Self::Documented,
+            "DocumentedFields" => Self::Fields,
+            "DocumentedVariants" => Self::Variants,
+            _ => None?,
+        };
+        Some((kind, is_opt))
+    }
+
+    fn derive_name(self, is_opt: bool) -> Ident {
+        let name = match self {
+            Self::Documented => "Documented",
+            Self::Fields => "DocumentedFields",
+            Self::Variants => "DocumentedVariants",
+        };
+        match is_opt {
+            true => format_ident!("{name}Opt"),
+            false => format_ident!("{name}"),
+        }
+    }
+}
+
+/// Parse the name of a derivable trait of this crate.
+fn parse_derivable(ident: &Ident) -> syn::Result<(DerivableKind, bool)> {
+    DerivableKind::from_name(&ident.to_string()).ok_or_else(|| {
+        Error::new_spanned(
+            ident,
+            "Unknown trait. Expecting one of `Documented`, `DocumentedOpt`, \
+            `DocumentedFields`, `DocumentedFieldsOpt`, \
+            `DocumentedVariants`, `DocumentedVariantsOpt`",
+        )
+    })
+}
+
+/// The traits to derive on each type, as given in the arguments of `auto`.
+///
+/// Expected parse stream format: `<TRAIT>, <TRAIT>, ...`
+pub struct AutoTraits(Vec<(DerivableKind, bool)>);
+impl Parse for AutoTraits {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let idents = Punctuated::<Ident, Token![,]>::parse_terminated(input)?;
+        if idents.is_empty() {
+            Err(input.error("Expecting at least one trait to derive"))?
+        }
+
+        let mut traits = vec![];
+        for ident in idents {
+            let (kind, is_opt) = parse_derivable(&ident)?;
+            if traits.iter().any(|&(k, _)| k == kind) {
+                Err(Error::new_spanned(
+                    ident,
+                    "Multiple flavours of the same trait",
+                ))?
+            }
+            traits.push((kind, is_opt));
+        }
+        Ok(Self(traits))
+    }
+}
+
+pub fn auto_impl(mut item: ItemMod, traits: AutoTraits) -> syn::Result<TokenStream> {
+    let Some((_, ref mut items)) = item.content else {
+        Err(Error::new_spanned(
+            &item,
+            "Only inline modules are supported, i.e. `mod name { ... }`",
+        ))?
+    };
+
+    let documented_module_path = crate_module_path();
+    for item in items.iter_mut() {
+        let (attrs, is_enum) = match item {
+            Item::Enum(item) => (&mut item.attrs, true),
+            Item::Struct(item) => (&mut item.attrs, false),
+            Item::Union(item) => (&mut item.attrs, false),
+            _ => continue,
+        };
+
+        // per-type opt-outs and overrides
+        let mut skip_all = false;
+        let mut skipped = vec![];
+        let mut force_opt = false;
+        for attr in attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("documented_auto"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    if meta.input.is_empty() || meta.input.peek(Token![,]) {
+                        skip_all = true;
+                        return Ok(());
+                    }
+                    meta.parse_nested_meta(|meta| {
+                        let ident = meta.path.require_ident()?;
+                        skipped.push(parse_derivable(ident)?.0);
+                        Ok(())
+                    })
+                } else if meta.path.is_ident("opt") {
+                    force_opt = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("Unknown option. Expecting `skip`, `skip(...)` or `opt`"))
+                }
+            })?;
+        }
+        attrs.retain(|attr| !attr.path().is_ident("documented_auto"));
+        if skip_all {
+            continue;
+        }
+
+        // traits that are already derived manually
+        let mut derived = vec![];
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("derive")) {
+            let paths =
+                attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)?;
+            derived.extend(paths.iter().filter_map(|path| {
+                let ident = &path.segments.last()?.ident;
+                DerivableKind::from_name(&ident.to_string()).map(|(kind, _)| kind)
+            }));
+        }
+
+        let derives = traits
+            .0
+            .iter()
+            .filter(|(kind, _)| is_enum || *kind != DerivableKind::Variants)
+            .filter(|(kind, _)| !skipped.contains(kind) && !derived.contains(kind))
+            .map(|&(kind, is_opt)| kind.derive_name(is_opt || force_opt))
+            .collect::<Vec<_>>();
+        if !derives.is_empty() {
+            // derive helper attributes must come after the derive
+            attrs.insert(
+                0,
+                parse_quote! { #[derive(#(#documented_module_path::#derives),*)] },
+            );
+        }
+    }
+
+    Ok(quote! { #item })
+}
@@ -1,5 +1,9 @@
+pub mod assoc_items;
 pub mod attr;
 #[cfg(feature = "customise")]
 pub mod customise_core;
 pub mod derive;
 pub mod derive_fields;
+pub mod derive_variants;
+pub mod file_docs;
+pub mod module;
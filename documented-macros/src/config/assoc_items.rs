@@ -0,0 +1,257 @@
+//! Specialised configuration for `documented_impl` and `documented_trait`.
+
+use syn::{Expr, Ident, Visibility};
+
+use crate::{config::derive_fields::RenameMode, util::TrimMode};
+
+#[cfg_attr(feature = "customise", optfield::optfield(
+    pub ImplBlockCustomisations,
+    attrs = (derive(Clone, Debug, Default, PartialEq, Eq)),
+    merge_fn = pub apply_impl_block_customisations,
+    doc = "Parsed user-defined customisations of configurable options.\n\
+    Specialised variant for the `impl` block of `documented_impl`.\n\
+    \n\
+    Expected parse stream format: `<KW> = <VAL>, <KW> = <VAL>, ...`"
+))]
+#[cfg_attr(feature = "customise", optfield::optfield(
+    pub TraitDefCustomisations,
+    attrs = (derive(Clone, Debug, Default, PartialEq, Eq)),
+    merge_fn = pub apply_trait_def_customisations,
+    doc = "Parsed user-defined customisations of configurable options.\n\
+    Specialised variant for the trait definition of `documented_trait`.\n\
+    \n\
+    Expected parse stream format: `<KW> = <VAL>, <KW> = <VAL>, ...`"
+))]
+#[cfg_attr(feature = "customise", optfield::optfield(
+    pub AssocItemCustomisations,
+    attrs = (derive(Clone, Debug, Default, PartialEq, Eq)),
+    merge_fn = pub apply_item_customisations,
+    doc = "Parsed user-defined customisations of configurable options.\n\
+    Specialised variant for each associated item of `documented_impl` and `documented_trait`.\n\
+    \n\
+    Expected parse stream format: `<KW> = <VAL>, <KW> = <VAL>, ...`"
+))]
+/// Configurable options for each associated item of an `impl` block or a
+/// trait definition via helper attributes.
+///
+/// Initial values are set to default.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssocItemsConfig {
+    // optfield does not rewrap `Option` by default, which is the desired behavior
+    // see https://docs.rs/optfield/latest/optfield/#rewrapping-option-fields
+    pub custom_vis: Option<Visibility>,
+    pub rename_mode: Option<RenameMode>,
+    pub default_value: Option<Expr>,
+    pub trim: TrimMode,
+    /// `None` means the `dyn Trait` impl is generated only if the trait is
+    /// dyn-compatible.
+    pub dyn_impl: Option<bool>,
+    pub map_name: Option<Ident>,
+}
+impl Default for AssocItemsConfig {
+    fn default() -> Self {
+        Self {
+            custom_vis: None,
+            rename_mode: None,
+            default_value: None,
+            trim: TrimMode::All,
+            dyn_impl: None,
+            map_name: None,
+        }
+    }
+}
+
+#[cfg(feature = "customise")]
+mod customise {
+    use syn::{
+        parse::{Parse, ParseStream},
+        punctuated::Punctuated,
+        Token,
+    };
+
+    use crate::config::{
+        assoc_items::{
+            AssocItemCustomisations, AssocItemsConfig, ImplBlockCustomisations,
+            TraitDefCustomisations,
+        },
+        customise_core::{ensure_unique_options, ConfigOption, ConfigOptionData},
+        derive_fields::RenameMode,
+    };
+
+    impl AssocItemsConfig {
+        /// Return a new instance of this config with `impl` block customisations applied.
+        pub fn with_impl_block_customisations(
+            &self,
+            customisations: ImplBlockCustomisations,
+        ) -> Self {
+            let mut new = self.clone();
+            new.apply_impl_block_customisations(customisations);
+            new
+        }
+
+        /// Return a new instance of this config with trait definition customisations applied.
+        pub fn with_trait_def_customisations(
+            &self,
+            customisations: TraitDefCustomisations,
+        ) -> Self {
+            let mut new = self.clone();
+            new.apply_trait_def_customisations(customisations);
+            new
+        }
+
+        /// Return a new instance of this config with item customisations applied.
+        pub fn with_item_customisations(&self, customisations: AssocItemCustomisations) -> Self {
+            let mut new = self.clone();
+            new.apply_item_customisations(customisations);
+            new
+        }
+    }
+
+    impl Parse for ImplBlockCustomisations {
+        fn parse(input: ParseStream) -> syn::Result<Self> {
+            use ConfigOptionData as Data;
+
+            let opts = Punctuated::<ConfigOption, Token![,]>::parse_terminated(input)?
+                .into_iter()
+                .collect::<Vec<_>>();
+
+            ensure_unique_options(&opts)?;
+
+            let mut config = Self::default();
+            for opt in opts {
+                match opt.data {
+                    Data::Flatten(..)
+                    | Data::DynImpl(..)
+                    | Data::SkipPrivate(..)
+                    | Data::Recursive(..)
+                    | Data::Lookup(..)
+                    | Data::Alias(..)
+                    | Data::CaseInsensitive(..)
+                    | Data::Skip(..)
+                    | Data::Include(..)
+                    | Data::DefaultFromType(..)
+                    | Data::Transparent(..)
+                    | Data::Delegate(..)
+                    | Data::PositionalNames(..) => Err(syn::Error::new(
+                        opt.span,
+                        "This config option is not applicable here",
+                    ))?,
+                    Data::Vis(vis) => {
+                        config.custom_vis.replace(vis);
+                    }
+                    Data::RenameAll(case) => {
+                        config.rename_mode.replace(RenameMode::ToCase(case.value()));
+                    }
+                    Data::Default(expr) => {
+                        config.default_value.replace(expr);
+                    }
+                    Data::Trim(trim) => {
+                        config.trim.replace(trim.value());
+                    }
+                    Data::Rename(name) => {
+                        config.map_name.replace(name.parse()?);
+                    }
+                }
+            }
+            Ok(config)
+        }
+    }
+
+    impl Parse for TraitDefCustomisations {
+        fn parse(input: ParseStream) -> syn::Result<Self> {
+            use ConfigOptionData as Data;
+
+            let opts = Punctuated::<ConfigOption, Token![,]>::parse_terminated(input)?
+                .into_iter()
+                .collect::<Vec<_>>();
+
+            ensure_unique_options(&opts)?;
+
+            let mut config = Self::default();
+            for opt in opts {
+                match opt.data {
+                    Data::Rename(..)
+                    | Data::Flatten(..)
+                    | Data::SkipPrivate(..)
+                    | Data::Recursive(..)
+                    | Data::Lookup(..)
+                    | Data::Alias(..)
+                    | Data::CaseInsensitive(..)
+                    | Data::Skip(..)
+                    | Data::Include(..)
+                    | Data::DefaultFromType(..)
+                    | Data::Transparent(..)
+                    | Data::Delegate(..)
+                    | Data::PositionalNames(..) => Err(syn::Error::new(
+                        opt.span,
+                        "This config option is not applicable here",
+                    ))?,
+                    Data::Vis(vis) => {
+                        config.custom_vis.replace(vis);
+                    }
+                    Data::RenameAll(case) => {
+                        config.rename_mode.replace(RenameMode::ToCase(case.value()));
+                    }
+                    Data::Default(expr) => {
+                        config.default_value.replace(expr);
+                    }
+                    Data::Trim(trim) => {
+                        config.trim.replace(trim.value());
+                    }
+                    Data::DynImpl(dyn_impl) => {
+                        config.dyn_impl.replace(dyn_impl.value());
+                    }
+                }
+            }
+            Ok(config)
+        }
+    }
+
+    impl TryFrom<Vec<ConfigOption>> for AssocItemCustomisations {
+        type Error = syn::Error;
+
+        /// Duplicate option rejection should be handled upstream.
+        fn try_from(opts: Vec<ConfigOption>) -> Result<Self, Self::Error> {
+            use ConfigOptionData as Data;
+
+            let mut config = Self::default();
+            for opt in opts {
+                match opt.data {
+                    Data::Vis(..)
+                    | Data::Flatten(..)
+                    | Data::DynImpl(..)
+                    | Data::SkipPrivate(..)
+                    | Data::Recursive(..)
+                    | Data::Lookup(..)
+                    | Data::Alias(..)
+                    | Data::CaseInsensitive(..)
+                    | Data::Skip(..)
+                    | Data::Include(..)
+                    | Data::DefaultFromType(..)
+                    | Data::Transparent(..)
+                    | Data::Delegate(..)
+                    | Data::PositionalNames(..) => Err(syn::Error::new(
+                        opt.span,
+                        "This config option is not applicable here",
+                    ))?,
+                    Data::RenameAll(case) => {
+                        // `rename` always has priority over `rename_all`
+                        if !matches!(config.rename_mode, Some(RenameMode::Custom(_))) {
+                            config.rename_mode.replace(RenameMode::ToCase(case.value()));
+                        }
+                    }
+                    Data::Rename(name) => {
+                        config.rename_mode.replace(RenameMode::Custom(name.value()));
+                    }
+                    Data::Default(expr) => {
+                        config.default_value.replace(expr);
+                    }
+                    Data::Trim(trim) => {
+                        config.trim.replace(trim.value());
+                    }
+                }
+            }
+            Ok(config)
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use syn::{Expr, Visibility};
 
+use crate::util::TrimMode;
+
 /// Configurable options for attribute macros via helper attributes.
 ///
 /// Initial values are set to default.
@@ -18,7 +20,7 @@ pub struct AttrConfig {
     pub custom_vis: Option<Visibility>,
     pub custom_name: Option<String>,
     pub default_value: Option<Expr>,
-    pub trim: bool,
+    pub trim: TrimMode,
 }
 impl Default for AttrConfig {
     fn default() -> Self {
@@ -26,7 +28,7 @@ impl Default for AttrConfig {
             custom_vis: None,
             custom_name: None,
             default_value: None,
-            trim: true,
+            trim: TrimMode::All,
         }
     }
 }
@@ -67,7 +69,20 @@ mod customise {
                 // I'd love to macro this if declarative macros can expand to a full match arm,
                 // but no: https://github.com/rust-lang/rfcs/issues/2654
                 match opt.data {
-                    Data::RenameAll(..) => Err(syn::Error::new(
+                    Data::RenameAll(..)
+                    | Data::Flatten(..)
+                    | Data::DynImpl(..)
+                    | Data::SkipPrivate(..)
+                    | Data::Recursive(..)
+                    | Data::Lookup(..)
+                    | Data::Alias(..)
+                    | Data::CaseInsensitive(..)
+                    | Data::Skip(..)
+                    | Data::Include(..)
+                    | Data::DefaultFromType(..)
+                    | Data::Transparent(..)
+                    | Data::Delegate(..)
+                    | Data::PositionalNames(..) => Err(syn::Error::new(
                         opt.span,
                         "This config option is not applicable here",
                     ))?,
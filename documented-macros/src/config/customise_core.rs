@@ -4,7 +4,12 @@ use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     spanned::Spanned,
-    Attribute, Error, Expr, LitBool, LitStr, Meta, Token, Visibility,
+    Attribute, Error, Expr, Lit, LitBool, LitStr, Meta, Token, Visibility,
+};
+
+use crate::{
+    config::derive_fields::IncludeMode,
+    util::{LookupMode, TrimMode},
 };
 
 mod kw {
@@ -15,6 +20,19 @@ mod kw {
     custom_keyword!(rename);
     custom_keyword!(default);
     custom_keyword!(trim);
+    custom_keyword!(flatten);
+    custom_keyword!(dyn_impl);
+    custom_keyword!(skip_private);
+    custom_keyword!(recursive);
+    custom_keyword!(lookup);
+    custom_keyword!(alias);
+    custom_keyword!(case_insensitive);
+    custom_keyword!(skip);
+    custom_keyword!(include);
+    custom_keyword!(default_from_type);
+    custom_keyword!(transparent);
+    custom_keyword!(delegate);
+    custom_keyword!(positional_names);
 
     // recognised old keywords
     // error when used
@@ -24,7 +42,7 @@ mod kw {
 /// A configuration option that includes the span info. Each kind of
 /// customisation struct may choose to accept or reject any of them.
 ///
-/// Expected parse stream format: `<KW> = <VAL>`.
+/// Expected parse stream format: `<KW> = <VAL>`, or just `<KW>` for flags.
 #[derive(Clone, Debug)]
 pub struct ConfigOption {
     /// The span over the keyword of the config option.
@@ -41,19 +59,46 @@ impl Parse for ConfigOption {
         let span = input.span();
 
         let kind = input.parse::<ConfigOptionKind>()?;
-        input.parse::<Token![=]>()?;
         let data = match kind {
-            Kind::Vis => Data::Vis(input.parse()?),
-            Kind::RenameAll => Data::RenameAll(input.parse()?),
-            Kind::Rename => Data::Rename(input.parse()?),
-            Kind::Default => Data::Default(input.parse()?),
-            Kind::Trim => Data::Trim(input.parse()?),
+            Kind::Vis => Data::Vis(parse_value(input)?),
+            Kind::RenameAll => Data::RenameAll(parse_value(input)?),
+            Kind::Rename => Data::Rename(parse_value(input)?),
+            Kind::Default => Data::Default(parse_value(input)?),
+            Kind::Trim => Data::Trim(parse_value(input)?),
+            Kind::Flatten => Data::Flatten(parse_flag(input)?),
+            Kind::DynImpl => Data::DynImpl(parse_flag(input)?),
+            Kind::SkipPrivate => Data::SkipPrivate(parse_flag(input)?),
+            Kind::Recursive => Data::Recursive(parse_flag(input)?),
+            Kind::Lookup => Data::Lookup(parse_value(input)?),
+            Kind::Alias => Data::Alias(parse_value(input)?),
+            Kind::CaseInsensitive => Data::CaseInsensitive(parse_flag(input)?),
+            Kind::Skip => Data::Skip(parse_flag(input)?),
+            Kind::Include => Data::Include(parse_value(input)?),
+            Kind::DefaultFromType => Data::DefaultFromType(parse_flag(input)?),
+            Kind::Transparent => Data::Transparent(parse_flag(input)?),
+            Kind::Delegate => Data::Delegate(parse_flag(input)?),
+            Kind::PositionalNames => Data::PositionalNames(parse_flag(input)?),
         };
 
         Ok(Self { span, data })
     }
 }
 
+/// Parse the `= <VAL>` part of a config option.
+fn parse_value<T: Parse>(input: ParseStream) -> syn::Result<T> {
+    input.parse::<Token![=]>()?;
+    input.parse()
+}
+
+/// Parse the optional `= <BOOL>` part of a flag. A bare flag means `true`.
+fn parse_flag(input: ParseStream) -> syn::Result<LitBool> {
+    if input.peek(Token![=]) {
+        parse_value(input)
+    } else {
+        Ok(LitBool::new(true, input.span()))
+    }
+}
+
 /// All supported cases of `rename_all`.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct LitCase(convert_case::Case<'static>);
@@ -93,6 +138,111 @@ impl LitCase {
     }
 }
 
+/// All supported modes of `trim`.
+///
+/// For backwards compatibility, `true` and `false` are accepted as aliases
+/// of `"all"` and `"none"` respectively.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LitTrimMode(TrimMode);
+impl Parse for LitTrimMode {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        const SUPPORTED_MODES: [(&str, TrimMode); 3] = [
+            ("all", TrimMode::All),
+            ("dedent", TrimMode::Dedent),
+            ("none", TrimMode::None),
+        ];
+
+        let mode = match input.parse::<Lit>()? {
+            Lit::Bool(arg) if arg.value() => TrimMode::All,
+            Lit::Bool(_) => TrimMode::None,
+            Lit::Str(arg) => {
+                let Some(mode) = SUPPORTED_MODES
+                    .into_iter()
+                    .find_map(|(name, mode)| (name == arg.value()).then_some(mode))
+                else {
+                    let options = SUPPORTED_MODES.map(|(name, _)| name).join(", ");
+                    Err(Error::new(
+                        arg.span(),
+                        format!("Trim mode must be a bool or one of {options}."),
+                    ))?
+                };
+                mode
+            }
+            other => Err(Error::new(
+                other.span(),
+                "Trim mode must be a bool or a string literal.",
+            ))?,
+        };
+
+        Ok(Self(mode))
+    }
+}
+impl LitTrimMode {
+    pub fn value(&self) -> TrimMode {
+        self.0
+    }
+}
+
+/// All supported backends of `lookup`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LitLookupMode(LookupMode);
+impl Parse for LitLookupMode {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        const SUPPORTED_MODES: [(&str, LookupMode); 4] = [
+            ("auto", LookupMode::Auto),
+            ("phf", LookupMode::Phf),
+            ("match", LookupMode::Match),
+            ("binary_search", LookupMode::BinarySearch),
+        ];
+
+        let arg = input.parse::<LitStr>()?;
+        let Some(mode) = SUPPORTED_MODES
+            .into_iter()
+            .find_map(|(name, mode)| (name == arg.value()).then_some(mode))
+        else {
+            let options = SUPPORTED_MODES.map(|(name, _)| name).join(", ");
+            Err(Error::new(
+                arg.span(),
+                format!("Lookup mode must be one of {options}."),
+            ))?
+        };
+        Ok(Self(mode))
+    }
+}
+impl LitLookupMode {
+    pub fn value(&self) -> LookupMode {
+        self.0
+    }
+}
+
+/// All supported filters of `include`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LitIncludeMode(IncludeMode);
+impl Parse for LitIncludeMode {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        const SUPPORTED_MODES: [(&str, IncludeMode); 2] =
+            [("all", IncludeMode::All), ("pub", IncludeMode::Pub)];
+
+        let arg = input.parse::<LitStr>()?;
+        let Some(mode) = SUPPORTED_MODES
+            .into_iter()
+            .find_map(|(name, mode)| (name == arg.value()).then_some(mode))
+        else {
+            let options = SUPPORTED_MODES.map(|(name, _)| name).join(", ");
+            Err(Error::new(
+                arg.span(),
+                format!("Include filter must be one of {options}."),
+            ))?
+        };
+        Ok(Self(mode))
+    }
+}
+impl LitIncludeMode {
+    pub fn value(&self) -> IncludeMode {
+        self.0
+    }
+}
+
 /// The data of all known configuration options.
 #[derive(Clone, Debug, PartialEq, Eq, strum::EnumDiscriminants)]
 #[strum_discriminants(
@@ -122,10 +272,75 @@ pub enum ConfigOptionData {
     /// E.g. `default = "not documented"`.
     Default(Expr),
 
-    /// Trim each line or not.
+    /// How to trim the lines of doc comments.
+    ///
+    /// E.g. `trim = false`, `trim = "dedent"`.
+    Trim(LitTrimMode),
+
+    /// Make the fields of a field's type accessible via dotted paths.
+    ///
+    /// E.g. `flatten`, `flatten = true`.
+    Flatten(LitBool),
+
+    /// Implement the traits of this crate for the trait object type.
+    ///
+    /// E.g. `dyn_impl = false`.
+    DynImpl(LitBool),
+
+    /// Exclude items with inherited (i.e. private) visibility.
+    ///
+    /// E.g. `skip_private`, `skip_private = true`.
+    SkipPrivate(LitBool),
+
+    /// Also include the items of nested inline modules.
+    ///
+    /// E.g. `recursive`, `recursive = true`.
+    Recursive(LitBool),
+
+    /// The backend used for looking up names.
+    ///
+    /// E.g. `lookup = "binary_search"`.
+    Lookup(LitLookupMode),
+
+    /// An additional name that the lookup accepts. Can be declared repeatedly.
+    ///
+    /// E.g. `alias = "listen_port"`.
+    Alias(LitStr),
+
+    /// Ignore ASCII case when looking up names.
+    ///
+    /// E.g. `case_insensitive`, `case_insensitive = true`.
+    CaseInsensitive(LitBool),
+
+    /// Exclude a field or variant.
+    ///
+    /// E.g. `skip`, `skip = false`.
+    Skip(LitBool),
+
+    /// Which fields to include, by visibility.
+    ///
+    /// E.g. `include = "pub"`.
+    Include(LitIncludeMode),
+
+    /// Use the docs of a field's type when doc comments are absent.
     ///
-    /// E.g. `trim = false`.
-    Trim(LitBool),
+    /// E.g. `default_from_type`, `default_from_type = true`.
+    DefaultFromType(LitBool),
+
+    /// Forward to the single field of a newtype instead of using its own docs.
+    ///
+    /// E.g. `transparent`, `transparent = true`.
+    Transparent(LitBool),
+
+    /// Use the docs of a variant's single field instead of its own docs.
+    ///
+    /// E.g. `delegate`, `delegate = false`.
+    Delegate(LitBool),
+
+    /// Name anonymous fields by their position.
+    ///
+    /// E.g. `positional_names`, `positional_names = true`.
+    PositionalNames(LitBool),
 }
 
 impl Parse for ConfigOptionKind {
@@ -146,6 +361,45 @@ impl Parse for ConfigOptionKind {
         } else if lookahead.peek(kw::trim) {
             input.parse::<kw::trim>()?;
             Self::Trim
+        } else if lookahead.peek(kw::flatten) {
+            input.parse::<kw::flatten>()?;
+            Self::Flatten
+        } else if lookahead.peek(kw::dyn_impl) {
+            input.parse::<kw::dyn_impl>()?;
+            Self::DynImpl
+        } else if lookahead.peek(kw::skip_private) {
+            input.parse::<kw::skip_private>()?;
+            Self::SkipPrivate
+        } else if lookahead.peek(kw::recursive) {
+            input.parse::<kw::recursive>()?;
+            Self::Recursive
+        } else if lookahead.peek(kw::lookup) {
+            input.parse::<kw::lookup>()?;
+            Self::Lookup
+        } else if lookahead.peek(kw::alias) {
+            input.parse::<kw::alias>()?;
+            Self::Alias
+        } else if lookahead.peek(kw::case_insensitive) {
+            input.parse::<kw::case_insensitive>()?;
+            Self::CaseInsensitive
+        } else if lookahead.peek(kw::skip) {
+            input.parse::<kw::skip>()?;
+            Self::Skip
+        } else if lookahead.peek(kw::include) {
+            input.parse::<kw::include>()?;
+            Self::Include
+        } else if lookahead.peek(kw::default_from_type) {
+            input.parse::<kw::default_from_type>()?;
+            Self::DefaultFromType
+        } else if lookahead.peek(kw::transparent) {
+            input.parse::<kw::transparent>()?;
+            Self::Transparent
+        } else if lookahead.peek(kw::delegate) {
+            input.parse::<kw::delegate>()?;
+            Self::Delegate
+        } else if lookahead.peek(kw::positional_names) {
+            input.parse::<kw::positional_names>()?;
+            Self::PositionalNames
         } else if lookahead.peek(kw::name) {
             Err(Error::new(
                 input.span(),
@@ -158,11 +412,12 @@ impl Parse for ConfigOptionKind {
     }
 }
 
-/// Make sure there are no duplicate options.
-/// Otherwise produces an error with detailed span info.
+/// Make sure there are no duplicate options, except for those that can be
+/// declared repeatedly. Otherwise produces an error with detailed span info.
 pub fn ensure_unique_options(opts: &[ConfigOption]) -> syn::Result<()> {
     for (kind, opts) in opts
         .iter()
+        .filter(|opt| !matches!(opt.data, ConfigOptionData::Alias(..)))
         .into_group_map_by(|opt| ConfigOptionKind::from(&opt.data))
         .into_iter()
     {
@@ -185,6 +440,21 @@ pub fn ensure_unique_options(opts: &[ConfigOption]) -> syn::Result<()> {
     Ok(())
 }
 
+/// Make sure `transparent` is not combined with other options, because all
+/// of them would be ignored when forwarding to the inner field.
+pub fn ensure_transparent_alone(opts: &[ConfigOption]) -> syn::Result<()> {
+    let transparent = opts
+        .iter()
+        .find(|opt| matches!(&opt.data, ConfigOptionData::Transparent(lit) if lit.value()));
+    match (transparent, opts.len()) {
+        (Some(opt), 2..) => Err(Error::new(
+            opt.span,
+            "transparent cannot be used together with other options",
+        )),
+        _ => Ok(()),
+    }
+}
+
 /// Parse a list of attributes into a validated customisation.
 ///
 /// `impl TryFrom<Vec<ConfigOption>>` and using this function is preferred to
@@ -5,6 +5,8 @@
 
 use syn::Expr;
 
+use crate::util::TrimMode;
+
 /// Configurable options for derive macros via helper attributes.
 ///
 /// Initial values are set to default.
@@ -21,18 +23,23 @@ pub struct DeriveConfig {
     // optfield does not rewrap `Option` by default, which is the desired behavior
     // see https://docs.rs/optfield/latest/optfield/#rewrapping-option-fields
     pub default_value: Option<Expr>,
-    pub trim: bool,
+    pub trim: TrimMode,
+    pub transparent: bool,
 }
 impl Default for DeriveConfig {
     fn default() -> Self {
-        Self { default_value: None, trim: true }
+        Self {
+            default_value: None,
+            trim: TrimMode::All,
+            transparent: false,
+        }
     }
 }
 
 #[cfg(feature = "customise")]
 mod customise {
     use crate::config::{
-        customise_core::{ConfigOption, ConfigOptionData},
+        customise_core::{ensure_transparent_alone, ConfigOption, ConfigOptionData},
         derive::{DeriveConfig, DeriveCustomisations},
     };
 
@@ -52,18 +59,38 @@ mod customise {
         fn try_from(opts: Vec<ConfigOption>) -> Result<Self, Self::Error> {
             use ConfigOptionData as Data;
 
+            ensure_transparent_alone(&opts)?;
+
             let mut config = Self::default();
             for opt in opts {
                 match opt.data {
-                    Data::Vis(..) | Data::RenameAll(..) | Data::Rename(..) => Err(
-                        syn::Error::new(opt.span, "This config option is not applicable here"),
-                    )?,
+                    Data::Vis(..)
+                    | Data::RenameAll(..)
+                    | Data::Rename(..)
+                    | Data::Flatten(..)
+                    | Data::DynImpl(..)
+                    | Data::SkipPrivate(..)
+                    | Data::Recursive(..)
+                    | Data::Lookup(..)
+                    | Data::Alias(..)
+                    | Data::CaseInsensitive(..)
+                    | Data::Skip(..)
+                    | Data::Include(..)
+                    | Data::DefaultFromType(..)
+                    | Data::Delegate(..)
+                    | Data::PositionalNames(..) => Err(syn::Error::new(
+                        opt.span,
+                        "This config option is not applicable here",
+                    ))?,
                     Data::Default(expr) => {
                         config.default_value.replace(expr);
                     }
                     Data::Trim(trim) => {
                         config.trim.replace(trim.value());
                     }
+                    Data::Transparent(transparent) => {
+                        config.transparent.replace(transparent.value());
+                    }
                 }
             }
             Ok(config)
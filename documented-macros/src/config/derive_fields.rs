@@ -3,6 +3,8 @@
 use convert_case::Case;
 use syn::Expr;
 
+use crate::util::{LookupMode, TrimMode};
+
 /// Defines how to rename a particular field.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[allow(dead_code)]
@@ -13,6 +15,17 @@ pub enum RenameMode {
     Custom(String),
 }
 
+/// Defines which fields to include, by visibility.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum IncludeMode {
+    /// Include all fields.
+    #[default]
+    All,
+    /// Include only fields declared `pub`. Enum variants are always public.
+    #[cfg_attr(not(feature = "customise"), allow(dead_code))]
+    Pub,
+}
+
 #[cfg_attr(feature = "customise", optfield::optfield(
     pub DeriveFieldsBaseCustomisations,
     attrs = (derive(Clone, Debug, Default, PartialEq, Eq)),
@@ -40,22 +53,43 @@ pub struct DeriveFieldsConfig {
     // see https://docs.rs/optfield/latest/optfield/#rewrapping-option-fields
     pub rename_mode: Option<RenameMode>,
     pub default_value: Option<Expr>,
-    pub trim: bool,
+    pub default_from_type: bool,
+    pub trim: TrimMode,
+    pub lookup: LookupMode,
+    pub case_insensitive: bool,
+    pub positional_names: bool,
+    pub include: IncludeMode,
+    pub flatten: bool,
+    pub aliases: Vec<String>,
+    pub transparent: bool,
+    /// `None` means to skip only if the field is `#[doc(hidden)]`.
+    pub skip: Option<bool>,
 }
 impl Default for DeriveFieldsConfig {
     fn default() -> Self {
         Self {
             rename_mode: None,
             default_value: None,
-            trim: true,
+            default_from_type: false,
+            trim: TrimMode::All,
+            lookup: LookupMode::default(),
+            case_insensitive: false,
+            positional_names: false,
+            include: IncludeMode::default(),
+            flatten: false,
+            aliases: vec![],
+            transparent: false,
+            skip: None,
         }
     }
 }
 
 #[cfg(feature = "customise")]
 mod customise {
+    use proc_macro2::Span;
+
     use crate::config::{
-        customise_core::{ConfigOption, ConfigOptionData},
+        customise_core::{ensure_transparent_alone, ConfigOption, ConfigOptionData},
         derive_fields::{
             DeriveFieldsBaseCustomisations, DeriveFieldsConfig, DeriveFieldsCustomisations,
             RenameMode,
@@ -79,11 +113,33 @@ mod customise {
             customisations: DeriveFieldsCustomisations,
         ) -> Self {
             let mut new = self.clone();
+            // `default` and `default_from_type` override each other, so that
+            // whichever is set on the field wins over the container
+            if customisations.default_value.is_some() {
+                new.default_from_type = false;
+            }
+            if customisations.default_from_type == Some(true) {
+                new.default_value = None;
+            }
             new.apply_field_customisations(customisations);
             new
         }
     }
 
+    /// `default` and `default_from_type` are mutually exclusive on the same level.
+    fn ensure_single_default(
+        has_default: bool,
+        default_from_type_span: Option<Span>,
+    ) -> syn::Result<()> {
+        match (has_default, default_from_type_span) {
+            (true, Some(span)) => Err(syn::Error::new(
+                span,
+                "default_from_type cannot be used together with default",
+            )),
+            _ => Ok(()),
+        }
+    }
+
     impl TryFrom<Vec<ConfigOption>> for DeriveFieldsBaseCustomisations {
         type Error = syn::Error;
 
@@ -91,10 +147,21 @@ mod customise {
         fn try_from(opts: Vec<ConfigOption>) -> Result<Self, Self::Error> {
             use ConfigOptionData as Data;
 
+            ensure_transparent_alone(&opts)?;
+
             let mut config = Self::default();
+            let mut default_from_type_span = None;
             for opt in opts {
                 match opt.data {
-                    Data::Vis(..) | Data::Rename(..) => Err(syn::Error::new(
+                    Data::Vis(..)
+                    | Data::Rename(..)
+                    | Data::Flatten(..)
+                    | Data::DynImpl(..)
+                    | Data::SkipPrivate(..)
+                    | Data::Recursive(..)
+                    | Data::Alias(..)
+                    | Data::Skip(..)
+                    | Data::Delegate(..) => Err(syn::Error::new(
                         opt.span,
                         "This config option is not applicable here",
                     ))?,
@@ -104,11 +171,33 @@ mod customise {
                     Data::Default(expr) => {
                         config.default_value.replace(expr);
                     }
+                    Data::DefaultFromType(default_from_type) => {
+                        if default_from_type.value() {
+                            default_from_type_span.replace(opt.span);
+                        }
+                        config.default_from_type.replace(default_from_type.value());
+                    }
                     Data::Trim(trim) => {
                         config.trim.replace(trim.value());
                     }
+                    Data::Lookup(lookup) => {
+                        config.lookup.replace(lookup.value());
+                    }
+                    Data::CaseInsensitive(case_insensitive) => {
+                        config.case_insensitive.replace(case_insensitive.value());
+                    }
+                    Data::Include(include) => {
+                        config.include.replace(include.value());
+                    }
+                    Data::Transparent(transparent) => {
+                        config.transparent.replace(transparent.value());
+                    }
+                    Data::PositionalNames(positional_names) => {
+                        config.positional_names.replace(positional_names.value());
+                    }
                 }
             }
+            ensure_single_default(config.default_value.is_some(), default_from_type_span)?;
             Ok(config)
         }
     }
@@ -121,9 +210,19 @@ mod customise {
             use ConfigOptionData as Data;
 
             let mut config = Self::default();
+            let mut default_from_type_span = None;
             for opt in opts {
                 match opt.data {
-                    Data::Vis(..) => Err(syn::Error::new(
+                    Data::Vis(..)
+                    | Data::DynImpl(..)
+                    | Data::SkipPrivate(..)
+                    | Data::Recursive(..)
+                    | Data::Lookup(..)
+                    | Data::CaseInsensitive(..)
+                    | Data::Include(..)
+                    | Data::Transparent(..)
+                    | Data::Delegate(..)
+                    | Data::PositionalNames(..) => Err(syn::Error::new(
                         opt.span,
                         "This config option is not applicable here",
                     ))?,
@@ -139,11 +238,30 @@ mod customise {
                     Data::Default(expr) => {
                         config.default_value.replace(expr);
                     }
+                    Data::DefaultFromType(default_from_type) => {
+                        if default_from_type.value() {
+                            default_from_type_span.replace(opt.span);
+                        }
+                        config.default_from_type.replace(default_from_type.value());
+                    }
                     Data::Trim(trim) => {
                         config.trim.replace(trim.value());
                     }
+                    Data::Flatten(flatten) => {
+                        config.flatten.replace(flatten.value());
+                    }
+                    Data::Alias(alias) => {
+                        config
+                            .aliases
+                            .get_or_insert_with(Vec::new)
+                            .push(alias.value());
+                    }
+                    Data::Skip(skip) => {
+                        config.skip.replace(skip.value());
+                    }
                 }
             }
+            ensure_single_default(config.default_value.is_some(), default_from_type_span)?;
             Ok(config)
         }
     }
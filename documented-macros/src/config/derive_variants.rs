@@ -0,0 +1,186 @@
+//! Specialised configuration for `DocumentedVariants` and `DocumentedVariantsOpt`.
+
+use syn::Expr;
+
+use crate::{
+    config::derive_fields::RenameMode,
+    util::{LookupMode, TrimMode},
+};
+
+#[cfg_attr(feature = "customise", optfield::optfield(
+    pub DeriveVariantsBaseCustomisations,
+    attrs = (derive(Clone, Debug, Default, PartialEq, Eq)),
+    merge_fn = pub apply_base_customisations,
+    doc = "Parsed user-defined customisations of configurable options.\n\
+    Specialised variant for the type base of `DocumentedVariants` and `DocumentedVariantsOpt`.\n\
+    \n\
+    Expected parse stream format: `<KW> = <VAL>, <KW> = <VAL>, ...`"
+))]
+#[cfg_attr(feature = "customise", optfield::optfield(
+    pub DeriveVariantsCustomisations,
+    attrs = (derive(Clone, Debug, Default, PartialEq, Eq)),
+    merge_fn = pub apply_variant_customisations,
+    doc = "Parsed user-defined customisations of configurable options.\n\
+    Specialised variant for each variant of `DocumentedVariants` and `DocumentedVariantsOpt`.\n\
+    \n\
+    Expected parse stream format: `<KW> = <VAL>, <KW> = <VAL>, ...`"
+))]
+/// Configurable options for each variant via helper attributes.
+///
+/// Initial values are set to default.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeriveVariantsConfig {
+    // optfield does not rewrap `Option` by default, which is the desired behavior
+    // see https://docs.rs/optfield/latest/optfield/#rewrapping-option-fields
+    pub rename_mode: Option<RenameMode>,
+    pub default_value: Option<Expr>,
+    pub trim: TrimMode,
+    pub lookup: LookupMode,
+    /// `None` means to skip only if the variant is `#[doc(hidden)]`.
+    pub skip: Option<bool>,
+    pub delegate: bool,
+}
+impl Default for DeriveVariantsConfig {
+    fn default() -> Self {
+        Self {
+            rename_mode: None,
+            default_value: None,
+            trim: TrimMode::All,
+            lookup: LookupMode::default(),
+            skip: None,
+            delegate: false,
+        }
+    }
+}
+
+#[cfg(feature = "customise")]
+mod customise {
+    use crate::config::{
+        customise_core::{ConfigOption, ConfigOptionData},
+        derive_fields::RenameMode,
+        derive_variants::{
+            DeriveVariantsBaseCustomisations, DeriveVariantsConfig, DeriveVariantsCustomisations,
+        },
+    };
+
+    impl DeriveVariantsConfig {
+        /// Return a new instance of this config with base customisations applied.
+        pub fn with_base_customisations(
+            &self,
+            customisations: DeriveVariantsBaseCustomisations,
+        ) -> Self {
+            let mut new = self.clone();
+            new.apply_base_customisations(customisations);
+            new
+        }
+
+        /// Return a new instance of this config with variant customisations applied.
+        pub fn with_variant_customisations(
+            &self,
+            customisations: DeriveVariantsCustomisations,
+        ) -> Self {
+            let mut new = self.clone();
+            new.apply_variant_customisations(customisations);
+            new
+        }
+    }
+
+    impl TryFrom<Vec<ConfigOption>> for DeriveVariantsBaseCustomisations {
+        type Error = syn::Error;
+
+        /// Duplicate option rejection should be handled upstream.
+        fn try_from(opts: Vec<ConfigOption>) -> Result<Self, Self::Error> {
+            use ConfigOptionData as Data;
+
+            let mut config = Self::default();
+            for opt in opts {
+                match opt.data {
+                    Data::Vis(..)
+                    | Data::Rename(..)
+                    | Data::Flatten(..)
+                    | Data::DynImpl(..)
+                    | Data::SkipPrivate(..)
+                    | Data::Recursive(..)
+                    | Data::Alias(..)
+                    | Data::CaseInsensitive(..)
+                    | Data::Skip(..)
+                    | Data::Include(..)
+                    | Data::DefaultFromType(..)
+                    | Data::Transparent(..)
+                    | Data::PositionalNames(..) => Err(syn::Error::new(
+                        opt.span,
+                        "This config option is not applicable here",
+                    ))?,
+                    Data::RenameAll(case) => {
+                        config.rename_mode.replace(RenameMode::ToCase(case.value()));
+                    }
+                    Data::Default(expr) => {
+                        config.default_value.replace(expr);
+                    }
+                    Data::Trim(trim) => {
+                        config.trim.replace(trim.value());
+                    }
+                    Data::Lookup(lookup) => {
+                        config.lookup.replace(lookup.value());
+                    }
+                    Data::Delegate(delegate) => {
+                        config.delegate.replace(delegate.value());
+                    }
+                }
+            }
+            Ok(config)
+        }
+    }
+
+    impl TryFrom<Vec<ConfigOption>> for DeriveVariantsCustomisations {
+        type Error = syn::Error;
+
+        /// Duplicate option rejection should be handled upstream.
+        fn try_from(opts: Vec<ConfigOption>) -> Result<Self, Self::Error> {
+            use ConfigOptionData as Data;
+
+            let mut config = Self::default();
+            for opt in opts {
+                match opt.data {
+                    Data::Vis(..)
+                    | Data::Flatten(..)
+                    | Data::DynImpl(..)
+                    | Data::SkipPrivate(..)
+                    | Data::Recursive(..)
+                    | Data::Lookup(..)
+                    | Data::Alias(..)
+                    | Data::CaseInsensitive(..)
+                    | Data::Include(..)
+                    | Data::DefaultFromType(..)
+                    | Data::Transparent(..)
+                    | Data::PositionalNames(..) => Err(syn::Error::new(
+                        opt.span,
+                        "This config option is not applicable here",
+                    ))?,
+                    Data::RenameAll(case) => {
+                        // `rename` always has priority over `rename_all`
+                        if !matches!(config.rename_mode, Some(RenameMode::Custom(_))) {
+                            config.rename_mode.replace(RenameMode::ToCase(case.value()));
+                        }
+                    }
+                    Data::Rename(name) => {
+                        config.rename_mode.replace(RenameMode::Custom(name.value()));
+                    }
+                    Data::Default(expr) => {
+                        config.default_value.replace(expr);
+                    }
+                    Data::Trim(trim) => {
+                        config.trim.replace(trim.value());
+                    }
+                    Data::Skip(skip) => {
+                        config.skip.replace(skip.value());
+                    }
+                    Data::Delegate(delegate) => {
+                        config.delegate.replace(delegate.value());
+                    }
+                }
+            }
+            Ok(config)
+        }
+    }
+}
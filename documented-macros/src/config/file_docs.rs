@@ -0,0 +1,88 @@
+//! Specialised configuration for `crate_docs` and `module_docs`.
+
+use syn::Expr;
+
+use crate::util::TrimMode;
+
+/// Configurable options for `crate_docs` and `module_docs` via macro
+/// arguments.
+///
+/// Initial values are set to default.
+#[cfg_attr(feature = "customise", optfield::optfield(
+    pub FileDocsCustomisations,
+    attrs = add(derive(Default)),
+    merge_fn = pub apply_customisations,
+    doc = "Parsed user-defined customisations of configurable options.\n\
+    \n\
+    Expected parse stream format: `<KW> = <VAL>, <KW> = <VAL>, ...`"
+))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileDocsConfig {
+    // optfield does not rewrap `Option` by default, which is the desired behavior
+    // see https://docs.rs/optfield/latest/optfield/#rewrapping-option-fields
+    pub default_value: Option<Expr>,
+    pub trim: TrimMode,
+}
+impl Default for FileDocsConfig {
+    fn default() -> Self {
+        Self { default_value: None, trim: TrimMode::All }
+    }
+}
+
+#[cfg(feature = "customise")]
+mod customise {
+    use crate::config::{
+        customise_core::{ensure_unique_options, ConfigOption, ConfigOptionData},
+        file_docs::{FileDocsConfig, FileDocsCustomisations},
+    };
+
+    impl FileDocsConfig {
+        /// Return a new instance of this config with customisations applied.
+        pub fn with_customisations(mut self, customisations: FileDocsCustomisations) -> Self {
+            self.apply_customisations(customisations);
+            self
+        }
+    }
+
+    impl TryFrom<Vec<ConfigOption>> for FileDocsCustomisations {
+        type Error = syn::Error;
+
+        fn try_from(opts: Vec<ConfigOption>) -> Result<Self, Self::Error> {
+            use ConfigOptionData as Data;
+
+            ensure_unique_options(&opts)?;
+
+            let mut config = Self::default();
+            for opt in opts {
+                match opt.data {
+                    Data::Vis(..)
+                    | Data::RenameAll(..)
+                    | Data::Rename(..)
+                    | Data::Flatten(..)
+                    | Data::DynImpl(..)
+                    | Data::SkipPrivate(..)
+                    | Data::Recursive(..)
+                    | Data::Lookup(..)
+                    | Data::Alias(..)
+                    | Data::CaseInsensitive(..)
+                    | Data::Skip(..)
+                    | Data::Include(..)
+                    | Data::DefaultFromType(..)
+                    | Data::Transparent(..)
+                    | Data::Delegate(..)
+                    | Data::PositionalNames(..) => Err(syn::Error::new(
+                        opt.span,
+                        "This config option is not applicable here",
+                    ))?,
+                    Data::Default(expr) => {
+                        config.default_value.replace(expr);
+                    }
+                    Data::Trim(trim) => {
+                        config.trim.replace(trim.value());
+                    }
+                }
+            }
+            Ok(config)
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Specialised configuration for `documented_module`.
+
+use syn::{parse_quote, Expr, Visibility};
+
+use crate::util::TrimMode;
+
+/// Configurable options for `documented_module` via attribute arguments.
+///
+/// Initial values are set to default.
+#[cfg_attr(feature = "customise", optfield::optfield(
+    pub ModuleCustomisations,
+    attrs = add(derive(Default)),
+    merge_fn = pub apply_customisations,
+    doc = "Parsed user-defined customisations of configurable options.\n\
+    \n\
+    Expected parse stream format: `<KW> = <VAL>, <KW> = <VAL>, ...`"
+))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModuleConfig {
+    pub custom_vis: Visibility,
+    pub default_value: Option<Expr>,
+    pub trim: TrimMode,
+    pub skip_private: bool,
+    pub recursive: bool,
+}
+impl Default for ModuleConfig {
+    fn default() -> Self {
+        Self {
+            custom_vis: parse_quote! { pub },
+            default_value: None,
+            trim: TrimMode::All,
+            skip_private: false,
+            recursive: false,
+        }
+    }
+}
+
+#[cfg(feature = "customise")]
+mod customise {
+    use syn::{
+        parse::{Parse, ParseStream},
+        punctuated::Punctuated,
+        Token,
+    };
+
+    use crate::config::{
+        customise_core::{ensure_unique_options, ConfigOption, ConfigOptionData},
+        module::{ModuleConfig, ModuleCustomisations},
+    };
+
+    impl ModuleConfig {
+        /// Return a new instance of this config with customisations applied.
+        pub fn with_customisations(mut self, customisations: ModuleCustomisations) -> Self {
+            self.apply_customisations(customisations);
+            self
+        }
+    }
+
+    impl Parse for ModuleCustomisations {
+        fn parse(input: ParseStream) -> syn::Result<Self> {
+            use ConfigOptionData as Data;
+
+            let opts = Punctuated::<ConfigOption, Token![,]>::parse_terminated(input)?
+                .into_iter()
+                .collect::<Vec<_>>();
+
+            ensure_unique_options(&opts)?;
+
+            let mut config = Self::default();
+            for opt in opts {
+                match opt.data {
+                    Data::RenameAll(..)
+                    | Data::Rename(..)
+                    | Data::Flatten(..)
+                    | Data::DynImpl(..)
+                    | Data::Lookup(..)
+                    | Data::Alias(..)
+                    | Data::CaseInsensitive(..)
+                    | Data::Skip(..)
+                    | Data::Include(..)
+                    | Data::DefaultFromType(..)
+                    | Data::Transparent(..)
+                    | Data::Delegate(..)
+                    | Data::PositionalNames(..) => Err(syn::Error::new(
+                        opt.span,
+                        "This config option is not applicable here",
+                    ))?,
+                    Data::Vis(vis) => {
+                        config.custom_vis.replace(vis);
+                    }
+                    Data::Default(expr) => {
+                        config.default_value.replace(expr);
+                    }
+                    Data::Trim(trim) => {
+                        config.trim.replace(trim.value());
+                    }
+                    Data::SkipPrivate(skip_private) => {
+                        config.skip_private.replace(skip_private.value());
+                    }
+                    Data::Recursive(recursive) => {
+                        config.recursive.replace(recursive.value());
+                    }
+                }
+            }
+            Ok(config)
+        }
+    }
+}
@@ -3,12 +3,12 @@
 //! All functions in this module use the dependency injection pattern to
 //! generate the correct trait implementation for both macro variants.
 
-use convert_case::Casing;
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens, TokenStreamExt};
 use syn::{
-    spanned::Spanned, Data, DataEnum, DataStruct, DataUnion, DeriveInput, Error, Expr, Fields,
-    Ident,
+    ext::IdentExt, parse_quote, punctuated::Punctuated, spanned::Spanned, Attribute, Data,
+    DataEnum, DataStruct, DataUnion, DeriveInput, Error, Expr, Field, Fields, Generics, Ident,
+    Meta, Token, Type, Visibility,
 };
 
 #[cfg(feature = "customise")]
@@ -16,9 +16,13 @@ use crate::config::customise_core::get_customisations_from_attrs;
 use crate::{
     config::{
         derive::DeriveConfig,
-        derive_fields::{DeriveFieldsConfig, RenameMode},
+        derive_fields::{DeriveFieldsConfig, IncludeMode},
+        derive_variants::DeriveVariantsConfig,
+    },
+    util::{
+        get_doc_meta, get_docs, get_name, lookup_index_tokens, member_docs_const_ident, DocContent,
+        TrimMode,
     },
-    util::{crate_module_path, get_docs, DocContent},
 };
 
 /// The type of the doc comment.
@@ -87,7 +91,7 @@ impl DocType {
 pub fn documented_impl(input: DeriveInput, docs_ty: DocType) -> syn::Result<TokenStream> {
     let trait_ident = docs_ty.trait_ident_for("Documented");
     let ident = &input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut generics = input.generics.clone();
 
     #[cfg(not(feature = "customise"))]
     let config = DeriveConfig::default();
@@ -95,8 +99,16 @@ pub fn documented_impl(input: DeriveInput, docs_ty: DocType) -> syn::Result<Toke
     let config = get_customisations_from_attrs(&input.attrs, "documented")
         .map(|c| DeriveConfig::default().with_customisations(c))?;
 
-    let docs = get_docs(&input.attrs, config.trim)
-        .and_then(|docs_opt| docs_ty.docs_handler_opt()(docs_opt, config.default_value, &input))?;
+    let docs = if config.transparent {
+        let ty = &newtype_field(&input)?.ty;
+        add_forwarding_bound(&mut generics, ty, quote! { documented::#trait_ident });
+        quote! { <#ty as documented::#trait_ident>::DOCS }
+    } else {
+        get_docs(&input.attrs, config.trim).and_then(|docs_opt| {
+            docs_ty.docs_handler_opt()(docs_opt, config.default_value, &input)
+        })?
+    };
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     Ok(quote! {
         #[automatically_derived]
@@ -110,7 +122,7 @@ pub fn documented_impl(input: DeriveInput, docs_ty: DocType) -> syn::Result<Toke
 pub fn documented_fields_impl(input: DeriveInput, docs_ty: DocType) -> syn::Result<TokenStream> {
     let trait_ident = docs_ty.trait_ident_for("DocumentedFields");
     let ident = &input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut generics = input.generics.clone();
 
     // `#[documented_fields(...)]` on container type
     #[cfg(not(feature = "customise"))]
@@ -118,71 +130,299 @@ pub fn documented_fields_impl(input: DeriveInput, docs_ty: DocType) -> syn::Resu
     #[cfg(feature = "customise")]
     let base_config = get_customisations_from_attrs(&input.attrs, "documented_fields")
         .map(|c| DeriveFieldsConfig::default().with_base_customisations(c))?;
+    if base_config.transparent {
+        return transparent_fields_impl(&input, docs_ty);
+    }
+
+    // the names of the fields (or variants) as written in Rust
+    let members = match &input.data {
+        Data::Enum(DataEnum { variants, .. }) => variants
+            .iter()
+            .map(|v| v.ident.unraw().to_string())
+            .collect(),
+        Data::Struct(DataStruct { fields, .. }) => fields
+            .iter()
+            .enumerate()
+            .map(|(i, f)| {
+                f.ident
+                    .as_ref()
+                    .map_or(i.to_string(), |i| i.unraw().to_string())
+            })
+            .collect(),
+        Data::Union(DataUnion { fields, .. }) => fields
+            .named
+            .iter()
+            .filter_map(|f| f.ident.as_ref().map(|i| i.unraw().to_string()))
+            .collect::<Vec<_>>(),
+    };
 
     let fields_attrs: Vec<_> = match input.data.clone() {
         Data::Enum(DataEnum { variants, .. }) => variants
             .into_iter()
-            .map(|v| (v.to_token_stream(), Some(v.ident), v.attrs))
+            .map(|v| {
+                (
+                    v.to_token_stream(),
+                    Some(v.ident),
+                    None,
+                    Some(v.fields),
+                    v.attrs,
+                    true,
+                )
+            })
             .collect(),
         Data::Struct(DataStruct { fields, .. }) => fields
             .into_iter()
-            .map(|f| (f.to_token_stream(), f.ident, f.attrs))
+            .map(|f| {
+                let is_pub = matches!(f.vis, Visibility::Public(_));
+                (
+                    f.to_token_stream(),
+                    f.ident,
+                    Some(f.ty),
+                    None,
+                    f.attrs,
+                    is_pub,
+                )
+            })
             .collect(),
         Data::Union(DataUnion { fields, .. }) => fields
             .named
             .into_iter()
-            .map(|f| (f.to_token_stream(), f.ident, f.attrs))
+            .map(|f| {
+                let is_pub = matches!(f.vis, Visibility::Public(_));
+                (
+                    f.to_token_stream(),
+                    f.ident,
+                    Some(f.ty),
+                    None,
+                    f.attrs,
+                    is_pub,
+                )
+            })
             .collect(),
     };
 
-    let (field_names, field_docs) = fields_attrs
+    let (members, field_names, field_docs, nested_lookups, field_aliases) = members
         .into_iter()
-        .map(|(span, ident, attrs)| {
-            #[cfg(not(feature = "customise"))]
-            let config = base_config.clone();
-            #[cfg(feature = "customise")]
-            let config = get_customisations_from_attrs(&attrs, "documented_fields")
-                .map(|c| base_config.with_field_customisations(c))?;
-            let name = match config.rename_mode {
-                None => ident.map(|ident| ident.to_string()),
-                Some(RenameMode::ToCase(case)) => {
-                    ident.map(|ident| ident.to_string().to_case(case))
+        .zip(fields_attrs)
+        .map(
+            |(member, (span, ident, ty, variant_fields, attrs, is_pub))| {
+                #[cfg(not(feature = "customise"))]
+                let config = base_config.clone();
+                #[cfg(feature = "customise")]
+                let config = get_customisations_from_attrs(&attrs, "documented_fields")
+                    .map(|c| base_config.with_field_customisations(c))?;
+                let doc_meta = get_doc_meta(&attrs)?;
+                let skip = config
+                    .skip
+                    .unwrap_or(doc_meta.hidden || (config.include == IncludeMode::Pub && !is_pub));
+                if skip {
+                    return Ok(None);
                 }
-                Some(RenameMode::Custom(name)) => Some(name),
-            };
-            get_docs(&attrs, config.trim)
-                .and_then(|docs_opt| {
-                    docs_ty.docs_handler_opt()(docs_opt, config.default_value, span)
-                })
-                .map(|docs| (name, docs))
-        })
+                let name = get_name(ident.as_ref(), config.rename_mode).or_else(|| {
+                    // anonymous fields are named by their position, like in `self.0`
+                    base_config.positional_names.then(|| member.clone())
+                });
+                let default_value = match (config.default_from_type, &ty) {
+                    (false, _) => config.default_value,
+                    (true, Some(ty)) => {
+                        let docs_trait_ident = docs_ty.trait_ident_for("Documented");
+                        add_forwarding_bound(
+                            &mut generics,
+                            ty,
+                            quote! { documented::#docs_trait_ident },
+                        );
+                        Some(parse_quote! { <#ty as documented::#docs_trait_ident>::DOCS })
+                    }
+                    (true, None) => Err(Error::new_spanned(
+                        &span,
+                        "default_from_type is only applicable to fields of structs and unions",
+                    ))?,
+                };
+                // the expression that looks up `field_path` inside this field, if possible
+                let nested_lookup = match (config.flatten, ty, variant_fields) {
+                    (false, _, None) => None,
+                    (true, Some(ty), _) => {
+                        add_forwarding_bound(
+                            &mut generics,
+                            &ty,
+                            quote! { documented::#trait_ident },
+                        );
+                        Some(quote! {
+                            <#ty as documented::#trait_ident>::get_field_docs(field_path)
+                        })
+                    }
+                    (false, _, Some(variant_fields)) if variant_fields.is_empty() => None,
+                    (false, _, Some(variant_fields)) => {
+                        let fields_docs = get_variant_fields_docs(&variant_fields, config.trim)?;
+                        let names = fields_docs.iter().map(|(name, _)| name);
+                        let match_arms = fields_docs.iter().map(|(name, docs)| match docs {
+                            Some(docs) => quote! { #name => Ok(#docs), },
+                            None => quote! {
+                                #name => Err(documented::Error::_private_no_doc_comments::<Self>(
+                                    field_path,
+                                )),
+                            },
+                        });
+                        Some(quote! {
+                            match field_path {
+                                #(#match_arms)*
+                                _ => Err(documented::Error::_private_no_such_field::<Self>(
+                                    field_path,
+                                    &[#(#names),*],
+                                )),
+                            }
+                        })
+                    }
+                    (true, None, _) => Err(Error::new_spanned(
+                        &span,
+                        "flatten is only applicable to fields of structs and unions",
+                    ))?,
+                };
+                let mut aliases = config.aliases;
+                aliases.extend(doc_meta.aliases);
+                get_docs(&attrs, config.trim)
+                    .and_then(|docs_opt| docs_ty.docs_handler_opt()(docs_opt, default_value, span))
+                    .map(|docs| Some((member, name, docs, nested_lookup, aliases)))
+            },
+        )
         .collect::<syn::Result<Vec<_>>>()?
         .into_iter()
-        .unzip::<_, _, Vec<_>, Vec<_>>();
+        // skipped fields are left out entirely, so indices only count the rest
+        .flatten()
+        .fold(
+            (vec![], vec![], vec![], vec![], vec![]),
+            |(mut members, mut names, mut docs, mut nested_lookups, mut aliases),
+             (member, name, doc, nested_lookup, field_aliases)| {
+                if let Some(lookup) = nested_lookup {
+                    nested_lookups.push((names.len(), lookup));
+                }
+                members.push(member);
+                names.push(name);
+                docs.push(doc);
+                aliases.push(field_aliases);
+                (members, names, docs, nested_lookups, aliases)
+            },
+        );
 
-    let (field_names, phf_match_arms) = field_names
+    // aligned with `FIELD_DOCS`, unlike `FIELD_NAMES`
+    let fields = field_names
+        .iter()
+        .zip(&field_docs)
+        .map(|(name, docs)| match name {
+            Some(name) => quote! { (Some(#name), #docs) },
+            None => quote! { (None, #docs) },
+        })
+        .collect::<Vec<_>>();
+
+    let (field_names, field_indices) = field_names
         .into_iter()
         .enumerate()
-        .filter_map(|(i, field)| field.map(|field| (i, field.as_str().to_owned())))
-        .map(|(i, name)| (name.clone(), quote! { #name => #i, }))
+        .filter_map(|(i, field)| field.map(|field| (field, i)))
         .unzip::<_, _, Vec<_>, Vec<_>>();
+    // every name and alias accepted by the lookups, normalised if needed
+    let lookup_entries = field_names
+        .iter()
+        .zip(field_indices.iter().copied())
+        .chain(
+            field_aliases
+                .iter()
+                .enumerate()
+                .flat_map(|(i, aliases)| aliases.iter().map(move |alias| (alias, i))),
+        )
+        .map(|(name, i)| {
+            if base_config.case_insensitive {
+                (name.to_ascii_lowercase(), i)
+            } else {
+                (name.clone(), i)
+            }
+        })
+        .collect::<Vec<_>>();
+    let get_index = lookup_index_tokens(
+        lookup_entries.iter().map(|(name, i)| (name.as_str(), *i)),
+        quote! { field_name },
+        base_config.lookup,
+    );
+    let get_index = if base_config.case_insensitive {
+        let max_len = lookup_entries
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or(0);
+        quote! {
+            let mut buf = [0u8; #max_len];
+            let field_name = documented::_private_ascii_lowercase(field_name.as_ref(), &mut buf)?;
+            #get_index
+        }
+    } else {
+        quote! {
+            let field_name = field_name.as_ref();
+            #get_index
+        }
+    };
+
+    // only generated when needed; the trait provides a default that finds nothing
+    let get_nested_fn = (!nested_lookups.is_empty()).then(|| {
+        let nested_match_arms = nested_lookups
+            .iter()
+            .map(|(i, lookup)| quote! { #i => Some(#lookup), });
+        quote! {
+            fn __documented_get_nested_field_docs(
+                index: usize,
+                field_path: &str,
+            ) -> Option<Result<&'static str, documented::Error>> {
+                match index {
+                    #(#nested_match_arms)*
+                    _ => None,
+                }
+            }
+        }
+    });
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let documented_module_path = crate_module_path();
+    // for compile-time checked access via `docs_of!`; these are inherent, so only one of
+    // the derives may generate them
+    let member_consts = matches!(docs_ty, DocType::Str).then(|| {
+        let consts = members.iter().enumerate().map(|(i, member)| {
+            let const_ident = member_docs_const_ident(member, Span::call_site());
+            quote! {
+                #[doc(hidden)]
+                #[allow(non_upper_case_globals)]
+                pub const #const_ident: #docs_ty =
+                    <Self as documented::#trait_ident>::FIELD_DOCS[#i];
+            }
+        });
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #(#consts)*
+            }
+        }
+    });
+
+    let case_insensitive = base_config.case_insensitive.then(|| {
+        quote! {
+            const __DOCUMENTED_CASE_INSENSITIVE: bool = true;
+        }
+    });
 
     Ok(quote! {
+        #member_consts
+
         #[automatically_derived]
         impl #impl_generics documented::#trait_ident for #ident #ty_generics #where_clause {
             const FIELD_NAMES: &'static [&'static str] = &[#(#field_names),*];
             const FIELD_DOCS: &'static [#docs_ty] = &[#(#field_docs),*];
+            const FIELDS: &'static [(Option<&'static str>, #docs_ty)] = &[#(#fields),*];
+            const FIELD_ALIASES: &'static [&'static [&'static str]] =
+                &[#(&[#(#field_aliases),*]),*];
+            #case_insensitive
 
             fn __documented_get_index<__Documented_T: AsRef<str>>(field_name: __Documented_T) -> Option<usize> {
-                use #documented_module_path::_private_phf_reexport_for_macro as phf;
-
-                static PHF: phf::Map<&'static str, usize> = phf::phf_map! {
-                    #(#phf_match_arms)*
-                };
-                PHF.get(field_name.as_ref()).copied()
+                #get_index
             }
+
+            #get_nested_fn
         }
     })
 }
@@ -190,15 +430,16 @@ pub fn documented_fields_impl(input: DeriveInput, docs_ty: DocType) -> syn::Resu
 /// Shared implementation of `DocumentedVariants` & `DocumentedVariantsOpt`.
 pub fn documented_variants_impl(input: DeriveInput, docs_ty: DocType) -> syn::Result<TokenStream> {
     let trait_ident = docs_ty.trait_ident_for("DocumentedVariants");
+    let docs_trait_ident = docs_ty.trait_ident_for("Documented");
     let ident = &input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut generics = input.generics.clone();
 
     // `#[documented_variants(...)]` on container type
     #[cfg(not(feature = "customise"))]
-    let base_config = DeriveConfig::default();
+    let base_config = DeriveVariantsConfig::default();
     #[cfg(feature = "customise")]
     let base_config = get_customisations_from_attrs(&input.attrs, "documented_variants")
-        .map(|c| DeriveConfig::default().with_customisations(c))?;
+        .map(|c| DeriveVariantsConfig::default().with_base_customisations(c))?;
 
     let variants = match input.data {
         Data::Enum(DataEnum { variants, .. }) => Ok(variants),
@@ -220,37 +461,291 @@ pub fn documented_variants_impl(input: DeriveInput, docs_ty: DocType) -> syn::Re
             let config = base_config.clone();
             #[cfg(feature = "customise")]
             let config = get_customisations_from_attrs(&v.attrs, "documented_variants")
-                .map(|c| base_config.with_customisations(c))?;
-            get_docs(&v.attrs, config.trim)
-                .and_then(|docs_opt| docs_ty.docs_handler_opt()(docs_opt, config.default_value, &v))
-                .map(|docs| (v.ident, v.fields, docs))
+                .map(|c| base_config.with_variant_customisations(c))?;
+            let name =
+                get_name(Some(&v.ident), config.rename_mode).expect("variants are always named");
+            let skip = config.skip.unwrap_or(get_doc_meta(&v.attrs)?.hidden);
+            let docs = if config.delegate {
+                let ty = match v.fields.iter().collect::<Vec<_>>()[..] {
+                    [field] => &field.ty,
+                    _ => Err(Error::new_spanned(
+                        &v,
+                        "delegate requires a variant with exactly one field",
+                    ))?,
+                };
+                add_forwarding_bound(&mut generics, ty, quote! { documented::#docs_trait_ident });
+                quote! { <#ty as documented::#docs_trait_ident>::DOCS }
+            } else {
+                let docs = get_docs(&v.attrs, config.trim)?;
+                if skip && docs.is_none() && config.default_value.is_none() {
+                    if let DocType::Str = docs_ty {
+                        Err(Error::new_spanned(
+                            &v,
+                            "Missing doc comments\n\
+                            Skipped variants still need doc comments or a default value, \
+                            because `get_variant_docs` can be called on them.",
+                        ))?
+                    }
+                }
+                docs_ty.docs_handler_opt()(docs, config.default_value, &v)?
+            };
+            // skipped variants are only reachable through an instance, so they
+            // have no name and their fields are not looked up
+            if skip {
+                return Ok((v.ident, None, v.fields, docs, vec![]));
+            }
+            let fields_docs = get_variant_fields_docs(&v.fields, config.trim)?;
+            Ok((v.ident, Some(name), v.fields, docs, fields_docs))
         })
         .collect::<syn::Result<Vec<_>>>()?;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let match_arms = variants_docs
-        .into_iter()
-        .map(|(ident, fields, docs)| {
+    // skipped variants have no name, and are left out of everything indexed by name
+    let (variant_names, variant_docs) = variants_docs
+        .iter()
+        .filter_map(|(_, name, _, docs, _)| Some((name.as_deref()?, docs)))
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+    let get_index = lookup_index_tokens(
+        variant_names.iter().copied().zip(0..),
+        quote! { variant_name.as_ref() },
+        base_config.lookup,
+    );
+
+    let (match_arms, (fields_match_arms, field_names_match_arms)) = variants_docs
+        .iter()
+        .map(|(ident, _, fields, docs, fields_docs)| {
             let pat = match fields {
                 Fields::Unit => quote! { Self::#ident },
                 Fields::Unnamed(_) => quote! { Self::#ident(..) },
                 Fields::Named(_) => quote! { Self::#ident{..} },
             };
-            quote! { #pat => #docs, }
+            let fields_docs_arms = fields_docs.iter().map(|(name, docs)| match docs {
+                Some(docs) => quote! { #name => Some(Some(#docs)), },
+                None => quote! { #name => Some(None), },
+            });
+            let field_names = fields_docs.iter().map(|(name, _)| name);
+            (
+                quote! { #pat => #docs, },
+                (
+                    quote! {
+                        #pat => match field_name {
+                            #(#fields_docs_arms)*
+                            _ => None,
+                        },
+                    },
+                    quote! { #pat => &[#(#field_names),*], },
+                ),
+            )
         })
-        .collect::<Vec<_>>();
+        .unzip::<_, _, Vec<_>, (Vec<_>, Vec<_>)>();
 
-    // IDEA: I'd like to use phf here, but it doesn't seem to be possible at the moment,
-    // because there isn't a way to get an enum's discriminant at compile time
+    // fieldless enums additionally get `const` accessors, because their variants can be
+    // cast to their discriminants; these are inherent, so only one of the derives may
+    // generate them
+    let is_fieldless = matches!(docs_ty, DocType::Str)
+        && !variants_docs.is_empty()
+        && variants_docs
+            .iter()
+            .all(|(_, _, fields, _, _)| matches!(fields, Fields::Unit));
+    let const_accessors = is_fieldless.then(|| {
+        let (idents, docs) = variants_docs
+            .iter()
+            .map(|(ident, _, _, docs, _)| (ident, docs))
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+        let (listed_idents, listed_docs) = variants_docs
+            .iter()
+            .filter(|(_, name, _, _, _)| name.is_some())
+            .map(|(ident, _, _, docs, _)| (ident, docs))
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+        // discriminants above `i128::MAX` would wrap around
+        let docs_for_discriminant = (!is_repr_u128(&input.attrs)).then(|| {
+            quote! {
+                /// Get the documentation on the variant with the given discriminant,
+                /// or `None` if there is no such variant or it is skipped.
+                pub const fn docs_for_discriminant(discriminant: i128) -> Option<#docs_ty> {
+                    #(
+                        if discriminant == Self::#listed_idents as i128 {
+                            return Some(#listed_docs);
+                        }
+                    )*
+                    None
+                }
+            }
+        });
+        quote! {
+            #[allow(dead_code)]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// The doc comments on each variant of this enum that is not skipped, in
+                /// declaration order.
+                pub const VARIANT_DOCS: &'static [#docs_ty] = &[#(#listed_docs),*];
+
+                /// Get the documentation on this enum variant in a `const` context.
+                pub const fn variant_docs_const(&self) -> #docs_ty {
+                    match self {
+                        #(Self::#idents => #docs,)*
+                    }
+                }
+
+                #docs_for_discriminant
+            }
+        }
+    });
+
+    // IDEA: I'd like to use phf for `get_variant_docs` too, but it doesn't seem to be possible
+    // at the moment, because there isn't a way to get the discriminant of an enum with fields
+    // at compile time
     // if this becomes possible in the future, or alternatively you have a good workaround,
     // improvement suggestions are more than welcomed
     Ok(quote! {
         #[automatically_derived]
         impl #impl_generics documented::#trait_ident for #ident #ty_generics #where_clause {
+            const VARIANT_NAMES: &'static [&'static str] = &[#(#variant_names),*];
+
             fn get_variant_docs(&self) -> #docs_ty {
                 match self {
                     #(#match_arms)*
                 }
             }
+
+            fn __documented_get_variant_field_docs(
+                &self,
+                field_name: &str,
+            ) -> Option<Option<&'static str>> {
+                match self {
+                    #(#fields_match_arms)*
+                }
+            }
+
+            fn __documented_variant_field_names(&self) -> &'static [&'static str] {
+                match self {
+                    #(#field_names_match_arms)*
+                }
+            }
+
+            fn __documented_get_variant_docs_by_name<__Documented_T: AsRef<str>>(
+                variant_name: __Documented_T,
+            ) -> Option<#docs_ty> {
+                let variant_docs: &[#docs_ty] = &[#(#variant_docs),*];
+                let index: Option<usize> = #get_index;
+                index.map(|i| variant_docs[i])
+            }
         }
+
+        #const_accessors
     })
 }
+
+/// Shared implementation of `DocumentedFields` & `DocumentedFieldsOpt` for
+/// `transparent` newtypes, which forward everything to the type of their field.
+fn transparent_fields_impl(input: &DeriveInput, docs_ty: DocType) -> syn::Result<TokenStream> {
+    let trait_ident = docs_ty.trait_ident_for("DocumentedFields");
+    let ident = &input.ident;
+
+    let field = newtype_field(input)?;
+    if let Some(attr) = field
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("documented_fields"))
+    {
+        Err(Error::new_spanned(
+            attr,
+            "The field of a transparent type cannot be customised",
+        ))?
+    }
+    let ty = &field.ty;
+
+    let mut generics = input.generics.clone();
+    add_forwarding_bound(&mut generics, ty, quote! { documented::#trait_ident });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics documented::#trait_ident for #ident #ty_generics #where_clause {
+            const FIELD_NAMES: &'static [&'static str] =
+                <#ty as documented::#trait_ident>::FIELD_NAMES;
+            const FIELD_DOCS: &'static [#docs_ty] = <#ty as documented::#trait_ident>::FIELD_DOCS;
+            const FIELDS: &'static [(Option<&'static str>, #docs_ty)] =
+                <#ty as documented::#trait_ident>::FIELDS;
+            const FIELD_ALIASES: &'static [&'static [&'static str]] =
+                <#ty as documented::#trait_ident>::FIELD_ALIASES;
+            const __DOCUMENTED_CASE_INSENSITIVE: bool =
+                <#ty as documented::#trait_ident>::__DOCUMENTED_CASE_INSENSITIVE;
+
+            fn __documented_get_index<__Documented_T: AsRef<str>>(field_name: __Documented_T) -> Option<usize> {
+                <#ty as documented::#trait_ident>::__documented_get_index(field_name)
+            }
+
+            fn __documented_get_nested_field_docs(
+                index: usize,
+                field_path: &str,
+            ) -> Option<Result<&'static str, documented::Error>> {
+                <#ty as documented::#trait_ident>::__documented_get_nested_field_docs(
+                    index,
+                    field_path,
+                )
+            }
+        }
+    })
+}
+
+/// Get the single field of a struct, which `transparent` forwards to.
+fn newtype_field(input: &DeriveInput) -> syn::Result<&Field> {
+    match &input.data {
+        Data::Struct(DataStruct { fields, .. }) if fields.len() == 1 => {
+            Ok(fields.iter().next().expect("there is exactly one field"))
+        }
+        _ => Err(Error::new(
+            input.ident.span(),
+            "transparent requires a struct with exactly one field",
+        )),
+    }
+}
+
+/// Whether the attributes include `#[repr(u128)]`.
+fn is_repr_u128(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+        .any(|meta| meta.path().is_ident("u128"))
+}
+
+/// Require `ty` to implement `bound` when forwarding to it.
+///
+/// This is only needed if the type being derived is generic, because `ty` may
+/// then depend on its type parameters. Otherwise the bound is checked where it
+/// is used anyway.
+fn add_forwarding_bound(generics: &mut Generics, ty: &Type, bound: TokenStream) {
+    if generics.type_params().next().is_some() {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote! { #ty: #bound });
+    }
+}
+
+/// Get the names and docs of all fields of an enum variant.
+///
+/// Unnamed fields are named by their position, i.e. `"0"`, `"1"`, etc.
+fn get_variant_fields_docs(
+    fields: &Fields,
+    trim: TrimMode,
+) -> syn::Result<Vec<(String, Option<TokenStream>)>> {
+    let mut fields_docs = vec![];
+    for (i, f) in fields.iter().enumerate() {
+        if get_doc_meta(&f.attrs)?.hidden {
+            continue;
+        }
+        let name = f
+            .ident
+            .as_ref()
+            .map_or_else(|| i.to_string(), |ident| ident.to_string());
+        let docs = get_docs(&f.attrs, trim)?.map(|docs| docs.into_token_stream());
+        fields_docs.push((name, docs));
+    }
+    Ok(fields_docs)
+}
@@ -0,0 +1,226 @@
+//! Implementation of the function-like macros.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+#[cfg(feature = "customise")]
+use syn::punctuated::Punctuated;
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+    Error, Ident, LitInt, LitStr, Token, TypePath,
+};
+
+#[cfg(feature = "customise")]
+use crate::config::customise_core::ConfigOption;
+use crate::{
+    config::file_docs::FileDocsConfig,
+    util::{get_file_docs, member_docs_const_ident},
+};
+
+/// The arguments of `crate_docs` and `module_docs`.
+///
+/// Expected parse stream format: `["<PATH>",] <KW> = <VAL>, <KW> = <VAL>, ...`
+pub struct FileDocsArgs {
+    path: Option<LitStr>,
+    config: FileDocsConfig,
+}
+impl Parse for FileDocsArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = if input.peek(LitStr) {
+            let path = input.parse()?;
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+            Some(path)
+        } else {
+            None
+        };
+
+        #[cfg(not(feature = "customise"))]
+        let config = FileDocsConfig::default();
+        #[cfg(feature = "customise")]
+        let config = {
+            let opts = Punctuated::<ConfigOption, Token![,]>::parse_terminated(input)?
+                .into_iter()
+                .collect::<Vec<_>>();
+            FileDocsConfig::default().with_customisations(opts.try_into()?)
+        };
+
+        if !input.is_empty() {
+            Err(input.error("Unexpected tokens"))?
+        }
+
+        Ok(Self { path, config })
+    }
+}
+
+pub fn crate_docs_impl(args: FileDocsArgs) -> syn::Result<TokenStream> {
+    if let Some(path) = args.path {
+        Err(Error::new(
+            path.span(),
+            "crate_docs does not accept a path; use module_docs instead",
+        ))?
+    }
+
+    let manifest_dir = manifest_dir()?;
+    let path = crate_root_candidates()
+        .into_iter()
+        .map(|path| manifest_dir.join(path))
+        .find(|path| path.is_file())
+        .ok_or_else(|| {
+            Error::new(
+                Span::call_site(),
+                "Cannot find the crate root at `src/lib.rs` or `src/main.rs`",
+            )
+        })?;
+
+    file_docs(&path, args.config, Span::call_site())
+}
+
+pub fn module_docs_impl(args: FileDocsArgs) -> syn::Result<TokenStream> {
+    let Some(path) = args.path else {
+        Err(Error::new(
+            Span::call_site(),
+            "Expecting the path of a source file, relative to `CARGO_MANIFEST_DIR`",
+        ))?
+    };
+
+    file_docs(
+        &manifest_dir()?.join(path.value()),
+        args.config,
+        path.span(),
+    )
+}
+
+/// The conventional locations of the root of the crate being compiled, most
+/// likely first.
+///
+/// Cargo sets `CARGO_BIN_NAME` when compiling a binary, in which case its own
+/// root is preferred over the library of the same package.
+fn crate_root_candidates() -> Vec<PathBuf> {
+    let mut candidates = vec![];
+    if let Ok(bin) = env::var("CARGO_BIN_NAME") {
+        if env::var("CARGO_PKG_NAME").is_ok_and(|pkg| pkg == bin) {
+            candidates.push(PathBuf::from("src/main.rs"));
+        }
+        candidates.push(PathBuf::from(format!("src/bin/{bin}.rs")));
+        candidates.push(PathBuf::from(format!("src/bin/{bin}/main.rs")));
+    }
+    candidates.push(PathBuf::from("src/lib.rs"));
+    candidates.push(PathBuf::from("src/main.rs"));
+    candidates
+}
+
+fn manifest_dir() -> syn::Result<PathBuf> {
+    env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .ok_or_else(|| Error::new(Span::call_site(), "`CARGO_MANIFEST_DIR` is not set"))
+}
+
+/// Get the inner doc comments (i.e. `//!` and `#![doc = "..."]`) of a source
+/// file as a string literal.
+fn file_docs(path: &Path, config: FileDocsConfig, span: Span) -> syn::Result<TokenStream> {
+    let display_path = path.display();
+    let src = fs::read_to_string(path)
+        .map_err(|err| Error::new(span, format!("Cannot read `{display_path}`: {err}")))?;
+    let file = syn::parse_file(&src)
+        .map_err(|err| Error::new(span, format!("Cannot parse `{display_path}`: {err}")))?;
+
+    let file_dir = path.parent().unwrap_or(path);
+    let docs = match (
+        get_file_docs(&file.attrs, config.trim, file_dir)?,
+        config.default_value,
+    ) {
+        (Some(docs), _) => {
+            // the included files are relative to the module file, so the
+            // evaluated macros cannot be emitted here as-is
+            let included = docs.included_files();
+            let Some(docs) = docs.as_known_str() else {
+                Err(Error::new(
+                    span,
+                    format!(
+                        "The docs of `{display_path}` cannot be evaluated ahead of the compiler"
+                    ),
+                ))?
+            };
+            quote! { #included #docs }
+        }
+        (None, Some(default)) => quote! { #default },
+        (None, None) => Err(Error::new(
+            span,
+            format!("Missing inner doc comments in `{display_path}`"),
+        ))?,
+    };
+
+    // make sure the docs are updated whenever the file changes
+    let path = path.to_string_lossy();
+    Ok(quote! {{
+        const _: &str = include_str!(#path);
+        #docs
+    }})
+}
+
+/// The argument of `docs_of`, i.e. a type followed by the name or index of one
+/// of its fields or variants.
+///
+/// Expected parse stream format: `<TYPE>::<FIELD>`
+pub struct MemberPath {
+    ty: TypePath,
+    member: Ident,
+}
+impl Parse for MemberPath {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let malformed = |span| Error::new(span, "Expecting a path like `Type::field`");
+        let mut tokens = input
+            .parse::<TokenStream>()?
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        // tuple fields are not valid path segments, so strip them beforehand
+        let index = match tokens.last() {
+            Some(TokenTree::Literal(lit)) => {
+                let index = syn::parse2::<LitInt>(lit.to_token_stream())?;
+                let sep = tokens.split_off(tokens.len().saturating_sub(3));
+                match sep.as_slice() {
+                    [TokenTree::Punct(a), TokenTree::Punct(b), _]
+                        if a.as_char() == ':' && b.as_char() == ':' => {}
+                    _ => Err(malformed(index.span()))?,
+                }
+                Some(index)
+            }
+            _ => None,
+        };
+
+        let mut ty = syn::parse2::<TypePath>(tokens.into_iter().collect())?;
+        let member = match index {
+            Some(index) => member_docs_const_ident(index.base10_digits(), index.span()),
+            None => {
+                let segment = ty
+                    .path
+                    .segments
+                    .pop()
+                    .filter(|segment| segment.arguments.is_empty())
+                    .ok_or_else(|| malformed(ty.span()))?;
+                ty.path.segments.pop_punct();
+                let name = segment.ident.unraw().to_string();
+                member_docs_const_ident(&name, segment.ident.span())
+            }
+        };
+        if ty.path.segments.is_empty() {
+            Err(malformed(member.span()))?
+        }
+
+        Ok(Self { ty, member })
+    }
+}
+
+pub fn docs_of_impl(path: MemberPath) -> syn::Result<TokenStream> {
+    let MemberPath { ty, member } = path;
+    Ok(quote! { <#ty>::#member })
+}
@@ -1,14 +1,23 @@
+//! Derive, attribute and function-like macros for `documented`.
+//!
+//! This crate is not meant to be used directly; use `documented` instead.
+
 mod attr_impl;
 mod config;
 mod derive_impl;
+mod fn_like_impl;
 pub(crate) mod util;
 
 use proc_macro::TokenStream;
 use syn::{parse_macro_input, Error};
 
 use crate::{
-    attr_impl::docs_const_impl,
-    derive_impl::{documented_fields_impl, documented_impl, documented_variants_impl, DocType},
+    attr_impl::{
+        auto_impl, docs_const_impl, documented_impl_impl, documented_module_impl,
+        documented_trait_impl, param_docs_impl,
+    },
+    derive_impl::{documented_fields_impl, documented_variants_impl, DocType},
+    fn_like_impl::{crate_docs_impl, docs_of_impl, module_docs_impl},
 };
 
 /// Derive proc-macro for `Documented` trait.
@@ -81,13 +90,89 @@ use crate::{
 /// # #[cfg(feature = "customise")]
 /// assert_eq!(Frankly::DOCS, "     Terrible.");
 /// ```
+///
+/// `trim = true` and `trim = false` are aliases of `trim = "all"` and
+/// `trim = "none"` respectively.
+///
+/// ## 3. remove only the common indentation, like rustdoc does:
+///
+/// ```rust
+/// # use documented::Documented;
+/// # #[cfg(feature = "customise")]
+/// /**
+///  * Indentation is preserved:
+///  *
+///  *     let in_code_blocks = true;
+///  */
+/// #[derive(Documented)]
+/// #[documented(trim = "dedent")]
+/// struct Rustdoc;
+///
+/// # #[cfg(feature = "customise")]
+/// assert_eq!(
+///     Rustdoc::DOCS,
+///     "Indentation is preserved:\n\n    let in_code_blocks = true;"
+/// );
+/// ```
+///
+/// This removes the common leading indentation (including the conventional
+/// space after `///`), the ` * ` prefixes of `/** */` blocks, and leading and
+/// trailing blank lines. It is available on all the other macros too.
+///
+/// ## 4. forward to the docs of a newtype's field:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// A user's unique identifier.
+/// #[derive(Documented)]
+/// struct Id(u64);
+///
+/// # #[cfg(feature = "customise")]
+/// /// Only visible in rustdoc.
+/// #[derive(Documented)]
+/// #[documented(transparent)]
+/// struct UserId(Id);
+///
+/// # #[cfg(feature = "customise")]
+/// assert_eq!(UserId::DOCS, "A user's unique identifier.");
+/// ```
+///
+/// The struct must have exactly one field, whose type implements `Documented`
+/// (or `DocumentedOpt` for `DocumentedOpt`). The struct's own doc comments are
+/// ignored, so this option cannot be combined with any other option.
+///
+/// This only affects `Documented`. To also forward the field docs, use the
+/// separate [`#[documented_fields(transparent)]`](macro@DocumentedFields#10-forward-to-the-fields-of-a-newtypes-field)
+/// attribute for `DocumentedFields`:
+///
+/// ```rust
+/// # use documented::{Documented, DocumentedFields};
+/// /// The user's settings.
+/// #[derive(Documented, DocumentedFields)]
+/// struct Settings {
+///     /// Whether to use dark mode.
+///     dark_mode: bool,
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// #[derive(Documented, DocumentedFields)]
+/// #[documented(transparent)]
+/// #[documented_fields(transparent)]
+/// struct UserSettings(Settings);
+///
+/// # #[cfg(feature = "customise")]
+/// {
+/// assert_eq!(UserSettings::DOCS, "The user's settings.");
+/// assert_eq!(UserSettings::FIELD_NAMES, ["dark_mode"]);
+/// }
+/// ```
 #[cfg_attr(not(feature = "customise"), proc_macro_derive(Documented))]
 #[cfg_attr(
     feature = "customise",
     proc_macro_derive(Documented, attributes(documented))
 )]
 pub fn documented(input: TokenStream) -> TokenStream {
-    documented_impl(parse_macro_input!(input), DocType::Str)
+    derive_impl::documented_impl(parse_macro_input!(input), DocType::Str)
         .unwrap_or_else(Error::into_compile_error)
         .into()
 }
@@ -101,7 +186,7 @@ pub fn documented(input: TokenStream) -> TokenStream {
     proc_macro_derive(DocumentedOpt, attributes(documented))
 )]
 pub fn documented_opt(input: TokenStream) -> TokenStream {
-    documented_impl(parse_macro_input!(input), DocType::OptStr)
+    derive_impl::documented_impl(parse_macro_input!(input), DocType::OptStr)
         .unwrap_or_else(Error::into_compile_error)
         .into()
 }
@@ -148,8 +233,58 @@ pub fn documented_opt(input: TokenStream) -> TokenStream {
 /// assert_eq!(BornIn69::get_field_docs("explosive"), Ok("Before what?"));
 /// assert_eq!(
 ///     BornIn69::get_field_docs("gotcha"),
-///     Err(Error::NoSuchField("gotcha".to_string()))
+///     Err(Error::NoSuchField("gotcha".into()))
+/// );
+/// ```
+///
+/// For enums, the fields of each variant can be looked up using a dotted
+/// path. Unnamed fields are named by their position. Note that unlike the
+/// variants themselves, these fields are not required to have doc comments.
+///
+/// ```rust
+/// # use documented::{DocumentedFields, Error};
+/// #[derive(DocumentedFields)]
+/// enum Move {
+///     /// Just a normal move.
+///     Normal {
+///         /// Where the piece goes.
+///         to: String,
+///         capture: bool,
+///     },
+///     /// The best move.
+///     Castle(
+///         /// Long or short.
+///         bool,
+///     ),
+/// }
+///
+/// assert_eq!(Move::get_field_docs("Normal.to"), Ok("Where the piece goes."));
+/// assert_eq!(
+///     Move::get_field_docs("Normal.capture"),
+///     Err(Error::NoDocComments("Normal.capture".into()))
 /// );
+/// assert_eq!(Move::get_field_docs("Castle.0"), Ok("Long or short."));
+/// ```
+///
+/// To look up docs by name in const context, e.g. to build static tables, use
+/// `documented::field_docs_const` (or `field_docs_opt_const` for
+/// `DocumentedFieldsOpt`) instead. It returns `None` for unknown names, and
+/// does not look into flattened fields or the fields of variants.
+///
+/// ```rust
+/// # use documented::{field_docs_const, DocumentedFields};
+/// #[derive(DocumentedFields)]
+/// struct Config {
+///     /// The port to listen on.
+///     port: u16,
+/// }
+///
+/// const HELP: [(&str, Option<&str>); 2] = [
+///     ("port", field_docs_const::<Config>("port")),
+///     ("host", field_docs_const::<Config>("host")),
+/// ];
+///
+/// assert_eq!(HELP, [("port", Some("The port to listen on.")), ("host", None)]);
 /// ```
 ///
 /// # Configuration
@@ -269,6 +404,262 @@ pub fn documented_opt(input: TokenStream) -> TokenStream {
 /// # #[cfg(feature = "customise")]
 /// assert_eq!(Frankly::FIELD_DOCS, ["     Delicious.", "I'm vegan."]);
 /// ```
+///
+/// ## 5. flatten a field's own fields into dotted paths:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// # #[cfg(feature = "customise")]
+/// #[derive(DocumentedFields)]
+/// struct Opening {
+///     /// The Jobava London.
+///     name: String,
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// #[derive(DocumentedFields)]
+/// struct Repertoire {
+///     /// Always play it.
+///     #[documented_fields(flatten)]
+///     white: Opening,
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// assert_eq!(Repertoire::get_field_docs("white"), Ok("Always play it."));
+/// # #[cfg(feature = "customise")]
+/// assert_eq!(
+///     Repertoire::get_field_docs("white.name"),
+///     Ok("The Jobava London.")
+/// );
+/// ```
+///
+/// The type of a flattened field must also implement [`DocumentedFields`]
+/// (or [`DocumentedFieldsOpt`], respectively). Flattening can be nested to any
+/// depth.
+///
+/// Note that only the direct fields are listed in `FIELD_NAMES` and
+/// `FIELD_DOCS`; the fields of a flattened field can only be looked up using
+/// `get_field_docs`.
+///
+/// This option is only available on each individual field of structs and
+/// unions.
+///
+/// ## 6. choose how `get_field_docs` looks up names:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// # #[cfg(feature = "customise")]
+/// #[derive(DocumentedFields)]
+/// #[documented_fields(lookup = "binary_search")]
+/// struct Clock {
+///     /// Time left for white.
+///     white: u32,
+///     /// Time left for black.
+///     black: u32,
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// assert_eq!(Clock::get_field_docs("black"), Ok("Time left for black."));
+/// ```
+///
+/// The available backends are:
+///
+/// - `"phf"`: a perfect hash map; requires the `phf` feature (enabled by
+///   default). It is the slowest to compile.
+/// - `"match"`: a `match` on the name. Fast to compile, and it was the
+///   fastest to look up in our (limited) benchmarks.
+/// - `"binary_search"`: a binary search over the names, sorted at compile
+///   time. It generates the least code.
+/// - `"auto"` (default): `"phf"` if the `phf` feature is enabled, otherwise
+///   `"match"`.
+///
+/// Lookup speed depends on the number and names of the fields, the compiler
+/// and the machine, so measure if it matters to you. See
+/// `documented-test/benches/lookup.rs` in the repository for the benchmarks
+/// and one set of results.
+///
+/// This option is only available on the container.
+///
+/// ## 7. accept aliases and ignore case in `get_field_docs`:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// # #[cfg(feature = "customise")]
+/// #[derive(DocumentedFields)]
+/// #[documented_fields(case_insensitive)]
+/// struct Listener {
+///     /// The port to listen on.
+///     #[documented_fields(alias = "listen_port", alias = "p")]
+///     port: u16,
+///     /// The host to bind to.
+///     #[doc(alias = "address")]
+///     host: String,
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// # {
+/// assert_eq!(Listener::get_field_docs("PORT"), Ok("The port to listen on."));
+/// assert_eq!(Listener::get_field_docs("Listen_Port"), Ok("The port to listen on."));
+/// assert_eq!(Listener::get_field_docs("address"), Ok("The host to bind to."));
+/// assert_eq!(Listener::FIELD_NAMES, ["port", "host"]);
+/// assert_eq!(Listener::FIELD_ALIASES, [&["listen_port", "p"][..], &["address"]]);
+/// # }
+/// ```
+///
+/// `alias` can be declared repeatedly, and is only available on each
+/// individual field. `#[doc(alias = "...")]` is always read, even without the
+/// `customise` feature. Aliases are not listed in `FIELD_NAMES`, but in
+/// `FIELD_ALIASES` instead.
+///
+/// `case_insensitive` ignores ASCII case when looking up names and aliases,
+/// and is only available on the container. Beyond the first segment of a
+/// dotted path, names are looked up as configured by the type they belong to,
+/// and the fields of enum variants are always matched exactly.
+///
+/// ## 8. leave out fields:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// # #[cfg(feature = "customise")]
+/// #[derive(DocumentedFields)]
+/// #[documented_fields(include = "pub")]
+/// pub struct Listener {
+///     /// The port to listen on.
+///     pub port: u16,
+///     /// Connections so far.
+///     connections: u64,
+///     #[documented_fields(skip)]
+///     pub scratch: Vec<u8>,
+///     /// The backlog size.
+///     #[documented_fields(skip = false)]
+///     backlog: u32,
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// assert_eq!(Listener::FIELD_NAMES, ["port", "backlog"]);
+/// # #[cfg(feature = "customise")]
+/// assert_eq!(Listener::FIELD_DOCS, ["The port to listen on.", "The backlog size."]);
+/// ```
+///
+/// Fields that are left out need no doc comments, and are absent from
+/// `FIELD_DOCS`, `FIELD_NAMES`, `FIELDS`, `FIELD_ALIASES`, the lookups, and
+/// `docs_of!`.
+/// In particular, the indices of `FIELD_DOCS` only count the remaining fields.
+///
+/// `include = "pub"` leaves out fields not declared `pub`, and is only
+/// available on the container. Enum variants are always public. Fields (and
+/// the fields of variants) with `#[doc(hidden)]` are left out automatically,
+/// even without the `customise` feature. `skip` overrides both, and is only
+/// available on each individual field.
+///
+/// ## 9. fall back to the docs of a field's type:
+///
+/// ```rust
+/// # use documented::{Documented, DocumentedFields};
+/// /// Where the data lives.
+/// #[derive(Documented)]
+/// struct DatabaseConfig {
+///     url: String,
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// #[derive(DocumentedFields)]
+/// #[documented_fields(default_from_type)]
+/// struct AppConfig {
+///     database: DatabaseConfig,
+///     /// How many workers to spawn.
+///     workers: u8,
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// assert_eq!(
+///     AppConfig::FIELD_DOCS,
+///     ["Where the data lives.", "How many workers to spawn."]
+/// );
+/// ```
+///
+/// Undocumented fields use `<FieldType as Documented>::DOCS` (or
+/// `DocumentedOpt` for `DocumentedFieldsOpt`), so their types must implement
+/// that trait. This option is available both on the container and on each
+/// individual field, but not on enums. It cannot be combined with `default` on
+/// the same level, and whichever of the two is set on a field takes priority
+/// over the container.
+///
+/// ## 10. forward to the fields of a newtype's field:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// #[derive(DocumentedFields)]
+/// struct Settings {
+///     /// Whether to use dark mode.
+///     dark_mode: bool,
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// #[derive(DocumentedFields)]
+/// #[documented_fields(transparent)]
+/// struct UserSettings(Settings);
+///
+/// # #[cfg(feature = "customise")]
+/// assert_eq!(
+///     UserSettings::get_field_docs("dark_mode"),
+///     Ok("Whether to use dark mode.")
+/// );
+/// ```
+///
+/// The struct must have exactly one field, whose type implements
+/// `DocumentedFields` (or `DocumentedFieldsOpt` for `DocumentedFieldsOpt`).
+/// All constants and lookups are those of the field's type, so this option
+/// cannot be combined with any other option, and the field cannot be
+/// customised. `docs_of!` is not available for the newtype, but is for the
+/// field's type.
+///
+/// `#[documented(transparent)]` does not affect this derive, and this option
+/// does not affect `Documented`; use both attributes to forward everything.
+///
+/// ## 11. name anonymous fields by position:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// # #[cfg(feature = "customise")]
+/// #[derive(DocumentedFields)]
+/// #[documented_fields(positional_names)]
+/// struct Point(
+///     /// Horizontal.
+///     f32,
+///     /// Vertical.
+///     #[documented_fields(rename = "y")]
+///     f32,
+/// );
+///
+/// # #[cfg(feature = "customise")]
+/// # {
+/// assert_eq!(Point::FIELD_NAMES, ["0", "y"]);
+/// assert_eq!(Point::get_field_docs("0"), Ok("Horizontal."));
+/// # }
+/// ```
+///
+/// Anonymous fields keep their position in the type as their name, even if
+/// fields before them are left out. A custom name set with `rename` takes
+/// priority. This option is only available on the container.
+///
+/// Without this option, anonymous fields are missing from `FIELD_NAMES`, so
+/// its indices do not line up with `FIELD_DOCS`. `FIELDS` always does, and
+/// lists each field's name (if any) together with its docs:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// #[derive(DocumentedFields)]
+/// struct Point(
+///     /// Horizontal.
+///     f32,
+///     /// Vertical.
+///     f32,
+/// );
+///
+/// assert!(Point::FIELD_NAMES.is_empty());
+/// assert_eq!(Point::FIELDS, [(None, "Horizontal."), (None, "Vertical.")]);
+/// ```
 #[cfg_attr(not(feature = "customise"), proc_macro_derive(DocumentedFields))]
 #[cfg_attr(
     feature = "customise",
@@ -313,6 +704,99 @@ pub fn documented_fields_opt(input: TokenStream) -> TokenStream {
 /// assert_eq!(NeverPlay::F6.get_variant_docs(), "I fell out of my chair.");
 /// ```
 ///
+/// You can also use `DocumentedVariants::get_variant_docs_by_name` to access
+/// a variant's documentation using its name, which is handy when you do not
+/// have an instance of the variant at hand.
+///
+/// ```rust
+/// # use documented::{DocumentedVariants, Error};
+/// #
+/// # #[derive(DocumentedVariants)]
+/// # enum NeverPlay {
+/// #     /// Terrible.
+/// #     F3,
+/// #     /// I fell out of my chair.
+/// #     F6,
+/// # }
+/// #
+/// assert_eq!(NeverPlay::VARIANT_NAMES, ["F3", "F6"]);
+/// assert_eq!(NeverPlay::get_variant_docs_by_name("F3"), Ok("Terrible."));
+/// assert_eq!(
+///     NeverPlay::get_variant_docs_by_name("Nf3"),
+///     Err(Error::NoSuchField("Nf3".into()))
+/// );
+/// ```
+///
+/// The docs on the fields of a variant are accessible using
+/// `DocumentedVariants::get_variant_field_docs`. Unnamed fields are named by
+/// their position. Note that unlike the variants themselves, these fields are
+/// not required to have doc comments.
+///
+/// ```rust
+/// use documented::{DocumentedVariants, Error};
+///
+/// #[derive(DocumentedVariants)]
+/// enum Move {
+///     /// Just a normal move.
+///     Normal {
+///         /// Where the piece goes.
+///         to: String,
+///         capture: bool,
+///     },
+///     /// The best move.
+///     Castle(
+///         /// Long or short.
+///         bool,
+///     ),
+/// }
+///
+/// let normal = Move::Normal { to: "f7".into(), capture: true };
+/// assert_eq!(normal.get_variant_field_docs("to"), Ok("Where the piece goes."));
+/// assert_eq!(
+///     normal.get_variant_field_docs("capture"),
+///     Err(Error::NoDocComments("capture".into()))
+/// );
+/// assert_eq!(Move::Castle(true).get_variant_field_docs("0"), Ok("Long or short."));
+/// ```
+///
+/// For fieldless enums (i.e. enums where every variant is a unit variant),
+/// the following inherent items are additionally generated, all of which can
+/// be used in `const` contexts:
+///
+/// - `VARIANT_DOCS`: the docs of each variant, in declaration order;
+/// - `variant_docs_const(&self)`: a `const fn` version of `get_variant_docs`;
+/// - `docs_for_discriminant(i128)`: lookup using the variant's discriminant,
+///   which honours explicitly set discriminants. This is not generated for
+///   `#[repr(u128)]` enums, whose discriminants may not fit in an `i128`.
+///
+/// ```rust
+/// use documented::DocumentedVariants;
+///
+/// #[derive(DocumentedVariants)]
+/// enum Rating {
+///     /// Still learning.
+///     Beginner = 800,
+///     /// Knows the London.
+///     Club = 1500,
+///     /// Plays the London.
+///     Master = 2200,
+/// }
+///
+/// const CLUB_DOCS: &str = Rating::Club.variant_docs_const();
+///
+/// assert_eq!(CLUB_DOCS, "Knows the London.");
+/// assert_eq!(
+///     Rating::VARIANT_DOCS,
+///     ["Still learning.", "Knows the London.", "Plays the London."]
+/// );
+/// assert_eq!(Rating::docs_for_discriminant(2200), Some("Plays the London."));
+/// assert_eq!(Rating::docs_for_discriminant(3000), None);
+/// ```
+///
+/// Because these are inherent items, only `DocumentedVariants` generates
+/// them; `DocumentedVariantsOpt` does not, so both can be derived on the same
+/// fieldless enum.
+///
 /// # Configuration
 ///
 /// With the `customise` feature enabled, you can customise this macro's
@@ -374,55 +858,222 @@ pub fn documented_fields_opt(input: TokenStream) -> TokenStream {
 /// # #[cfg(feature = "customise")]
 /// assert_eq!(Always::Retreat.get_variant_docs(), "Like a Frenchman.");
 /// ```
-#[cfg_attr(not(feature = "customise"), proc_macro_derive(DocumentedVariants))]
-#[cfg_attr(
-    feature = "customise",
-    proc_macro_derive(DocumentedVariants, attributes(documented_variants))
-)]
-pub fn documented_variants(input: TokenStream) -> TokenStream {
-    documented_variants_impl(parse_macro_input!(input), DocType::Str)
-        .unwrap_or_else(Error::into_compile_error)
-        .into()
-}
-
-/// Derive proc-macro for `DocumentedVariantsOpt` trait.
 ///
-/// See [`DocumentedVariants`] for usage.
-#[cfg_attr(not(feature = "customise"), proc_macro_derive(DocumentedVariantsOpt))]
-#[cfg_attr(
-    feature = "customise",
-    proc_macro_derive(DocumentedVariantsOpt, attributes(documented_variants))
-)]
-pub fn documented_variants_opt(input: TokenStream) -> TokenStream {
-    documented_variants_impl(parse_macro_input!(input), DocType::OptStr)
-        .unwrap_or_else(Error::into_compile_error)
-        .into()
-}
-
-/// Macro to extract the documentation on any item that accepts doc comments
-/// and store it in a const variable.
+/// ## 3. set a different case convention for `get_variant_docs_by_name`:
 ///
-/// By default, this const variable inherits visibility from its parent item.
-/// This can be manually configured; see configuration section below.
+/// ```rust
+/// # use documented::DocumentedVariants;
+/// # #[cfg(feature = "customise")]
+/// #[derive(DocumentedVariants)]
+/// #[documented_variants(rename_all = "kebab-case")]
+/// enum Opening {
+///     /// Nobody knows this line.
+///     BongCloud,
+///     /// Trust me.
+///     #[documented_variants(rename_all = "SCREAMING_SNAKE_CASE")]
+///     EnglundGambit,
+/// }
 ///
-/// # Examples
+/// # #[cfg(feature = "customise")]
+/// assert_eq!(
+///     Opening::get_variant_docs_by_name("bong-cloud"),
+///     Ok("Nobody knows this line.")
+/// );
+/// # #[cfg(feature = "customise")]
+/// assert_eq!(
+///     Opening::get_variant_docs_by_name("ENGLUND_GAMBIT"),
+///     Ok("Trust me.")
+/// );
+/// ```
 ///
-/// ```rust
-/// use documented::docs_const;
+/// ## 4. set a custom name for a specific variant for `get_variant_docs_by_name`:
 ///
-/// /// This is a test function
-/// #[docs_const]
-/// fn test_fn() {}
+/// ```rust
+/// # use documented::DocumentedVariants;
+/// # #[cfg(feature = "customise")]
+/// #[derive(DocumentedVariants)]
+/// enum GameResult {
+///     /// That's just how it goes.
+///     #[documented_variants(rename = "1-0")]
+///     WhiteWins,
+///     /// Happens to the best of us.
+///     #[documented_variants(rename = "0-1")]
+///     BlackWins,
+/// }
 ///
-/// assert_eq!(TEST_FN_DOCS, "This is a test function");
+/// # #[cfg(feature = "customise")]
+/// assert_eq!(
+///     GameResult::get_variant_docs_by_name("1-0"),
+///     Ok("That's just how it goes.")
+/// );
 /// ```
 ///
-/// # Configuration
+/// Obviously this option is only available on each individual variant.
+/// It makes no sense on the container.
 ///
-/// With the `customise` feature enabled, you can customise this macro's
-/// behaviour using attribute arguments.
+/// This option also always takes priority over `rename_all`.
 ///
-/// Multiple option can be specified in a list:
+/// ## 5. choose how `get_variant_docs_by_name` looks up names:
+///
+/// ```rust
+/// # use documented::DocumentedVariants;
+/// # #[cfg(feature = "customise")]
+/// #[derive(DocumentedVariants)]
+/// #[documented_variants(lookup = "match")]
+/// enum Piece {
+///     /// Moves diagonally.
+///     Bishop,
+///     /// Moves in an L.
+///     Knight,
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// assert_eq!(Piece::get_variant_docs_by_name("Knight"), Ok("Moves in an L."));
+/// ```
+///
+/// See the same option of [`DocumentedFields`](macro@DocumentedFields) for the
+/// available backends.
+///
+/// This option is only available on the container.
+///
+/// ## 6. leave out variants from name lookups:
+///
+/// ```rust
+/// # use documented::{DocumentedVariants, Error};
+/// # #[cfg(feature = "customise")]
+/// #[derive(DocumentedVariants)]
+/// enum Piece {
+///     /// Moves diagonally.
+///     Bishop,
+///     #[documented_variants(skip, default = "Not a real piece.")]
+///     Joker,
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// # {
+/// assert_eq!(Piece::VARIANT_NAMES, ["Bishop"]);
+/// assert_eq!(
+///     Piece::get_variant_docs_by_name("Joker"),
+///     Err(Error::NoSuchField("Joker".into()))
+/// );
+/// assert_eq!(Piece::Joker.get_variant_docs(), "Not a real piece.");
+/// # }
+/// ```
+///
+/// Skipped variants are absent from `VARIANT_NAMES`, `VARIANT_DOCS`, the name
+/// lookups and `docs_for_discriminant`, and their fields cannot be looked up.
+/// Because `get_variant_docs` can still be called on them, they still return
+/// their doc comments or default value from it, and from `variant_docs_const`.
+/// So for `DocumentedVariants`, they still need one of the two.
+///
+/// Variants (and the fields of variants) with `#[doc(hidden)]` are skipped
+/// automatically, even without the `customise` feature. `skip = false`
+/// overrides this. This option is only available on each individual variant.
+///
+/// ## 7. use the docs of a variant's payload:
+///
+/// ```rust
+/// # use documented::{Documented, DocumentedVariants};
+/// /// A user signed in.
+/// #[derive(Documented)]
+/// struct LoginEvent {
+///     user: String,
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// #[derive(DocumentedVariants)]
+/// enum Event {
+///     #[documented_variants(delegate)]
+///     Login(LoginEvent),
+///     /// The server shut down.
+///     Shutdown,
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// # {
+/// let login = Event::Login(LoginEvent { user: "ferris".into() });
+/// assert_eq!(login.get_variant_docs(), "A user signed in.");
+/// assert_eq!(
+///     Event::get_variant_docs_by_name("Login"),
+///     Ok("A user signed in.")
+/// );
+/// # }
+/// ```
+///
+/// Delegated variants must have exactly one field, whose type implements
+/// `Documented` (or `DocumentedOpt` for `DocumentedVariantsOpt`). Their own doc
+/// comments and default value are ignored. This option is available both on
+/// the container and on each individual variant, where `delegate = false` opts
+/// out.
+#[cfg_attr(not(feature = "customise"), proc_macro_derive(DocumentedVariants))]
+#[cfg_attr(
+    feature = "customise",
+    proc_macro_derive(DocumentedVariants, attributes(documented_variants))
+)]
+pub fn documented_variants(input: TokenStream) -> TokenStream {
+    documented_variants_impl(parse_macro_input!(input), DocType::Str)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derive proc-macro for `DocumentedVariantsOpt` trait.
+///
+/// See [`DocumentedVariants`] for usage.
+#[cfg_attr(not(feature = "customise"), proc_macro_derive(DocumentedVariantsOpt))]
+#[cfg_attr(
+    feature = "customise",
+    proc_macro_derive(DocumentedVariantsOpt, attributes(documented_variants))
+)]
+pub fn documented_variants_opt(input: TokenStream) -> TokenStream {
+    documented_variants_impl(parse_macro_input!(input), DocType::OptStr)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Macro to extract the documentation on any item that accepts doc comments
+/// and store it in a const variable.
+///
+/// By default, this const variable inherits visibility from its parent item.
+/// This can be manually configured; see configuration section below.
+///
+/// # Examples
+///
+/// ```rust
+/// use documented::docs_const;
+///
+/// /// This is a test function
+/// #[docs_const]
+/// fn test_fn() {}
+///
+/// assert_eq!(TEST_FN_DOCS, "This is a test function");
+/// ```
+///
+/// When used on an `extern` block, a constant is generated for each function,
+/// static and type in the block instead. The options `vis`, `default` and
+/// `trim` apply to each of them, while `rename` is not allowed.
+///
+/// ```rust
+/// use documented::docs_const;
+///
+/// #[docs_const]
+/// extern "C" {
+///     /// Compute the absolute value of an integer.
+///     fn abs(input: i32) -> i32;
+///
+///     /// The last error number.
+///     static errno: i32;
+/// }
+///
+/// assert_eq!(ABS_DOCS, "Compute the absolute value of an integer.");
+/// assert_eq!(ERRNO_DOCS, "The last error number.");
+/// ```
+///
+/// # Configuration
+///
+/// With the `customise` feature enabled, you can customise this macro's
+/// behaviour using attribute arguments.
+///
+/// Multiple option can be specified in a list:
 /// `#[docs_const(vis = pub(crate), trim = false)]`.
 ///
 /// If there are other configuration options you wish to have, please submit an
@@ -498,3 +1149,704 @@ pub fn docs_const(#[allow(unused_variables)] attr: TokenStream, item: TokenStrea
 
     ts.unwrap_or_else(Error::into_compile_error).into()
 }
+
+/// Macro to collect the documentation of a function's parameters and store it
+/// in a const variable.
+///
+/// Rust does not allow doc comments on parameters, so their docs are taken from
+/// `#[param_doc = "..."]` attributes on the parameters, and from the list in the
+/// `# Arguments` section of the function's own docs. The former takes priority.
+/// Referencing a nonexistent parameter in `# Arguments` is a compile error.
+///
+/// The const variable is a `documented::DocsMap` keyed by parameter name,
+/// containing the documented parameters in order of declaration. By default, it
+/// inherits visibility from the function.
+///
+/// When used on a method, the const variable becomes an associated constant of
+/// the `impl` block, e.g. `Self::RUN_PARAM_DOCS`.
+///
+/// # Examples
+///
+/// ```rust
+/// use documented::param_docs;
+///
+/// /// Move a piece.
+/// ///
+/// /// # Arguments
+/// ///
+/// /// * `from` - The square to move from.
+/// /// * `to` - The square to move to.
+/// ///   Must be reachable, obviously.
+/// #[param_docs]
+/// fn make_move(
+///     from: &str,
+///     to: &str,
+///     #[param_doc = "The piece to promote to, if any."] promotion: Option<char>,
+/// ) {
+/// }
+///
+/// assert_eq!(MAKE_MOVE_PARAM_DOCS.get("from"), Some("The square to move from."));
+/// assert_eq!(
+///     MAKE_MOVE_PARAM_DOCS.get("to"),
+///     Some("The square to move to.\nMust be reachable, obviously.")
+/// );
+/// assert_eq!(
+///     MAKE_MOVE_PARAM_DOCS.get("promotion"),
+///     Some("The piece to promote to, if any.")
+/// );
+/// ```
+///
+/// # Configuration
+///
+/// With the `customise` feature enabled, the options `vis`, `rename`, `default`
+/// and `trim` are available, and work the same as they do for
+/// [`macro@docs_const`]. `default` is used for undocumented parameters, which
+/// are otherwise omitted.
+///
+/// ```rust
+/// # use documented::param_docs;
+/// # #[cfg(feature = "customise")]
+/// #[param_docs(rename = "ARGS", default = "Self-explanatory.")]
+/// fn castle(#[param_doc = "Castle queenside?"] long: bool, king: char) {}
+///
+/// # #[cfg(feature = "customise")]
+/// assert_eq!(
+///     ARGS.entries(),
+///     [("long", "Castle queenside?"), ("king", "Self-explanatory.")]
+/// );
+/// ```
+#[proc_macro_attribute]
+pub fn param_docs(#[allow(unused_variables)] attr: TokenStream, item: TokenStream) -> TokenStream {
+    #[cfg(not(feature = "customise"))]
+    let ts = param_docs_impl(parse_macro_input!(item));
+    #[cfg(feature = "customise")]
+    let ts = param_docs_impl(parse_macro_input!(item), parse_macro_input!(attr));
+
+    ts.unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Macro to extract the documentation on each method, associated constant and
+/// associated type of an `impl` block, and store them in associated constants.
+///
+/// For each item, a constant named after it (e.g. `RUN_DOCS` for `fn run`) is
+/// generated with the same visibility as the item. Additionally, a
+/// `documented::DocsMap` named `METHOD_DOCS` is generated, which allows
+/// looking up the docs of all items by name.
+///
+/// Both are placed in a separate inherent `impl` block of the same type, so
+/// this macro also works on trait implementations for local types.
+///
+/// It is a compile error for two items to produce the same constant name, e.g.
+/// `fn run` and `const RUN`, which would both produce `RUN_DOCS`.
+///
+/// # Examples
+///
+/// ```rust
+/// use documented::documented_impl;
+///
+/// struct Dispatcher;
+///
+/// #[documented_impl]
+/// impl Dispatcher {
+///     /// The number of commands.
+///     pub const COUNT: usize = 2;
+///
+///     /// Start the engine.
+///     pub fn run(&self) {}
+///
+///     /// Stop the engine.
+///     pub fn stop(&self) {}
+/// }
+///
+/// assert_eq!(Dispatcher::RUN_DOCS, "Start the engine.");
+/// assert_eq!(Dispatcher::COUNT_DOCS, "The number of commands.");
+/// assert_eq!(Dispatcher::METHOD_DOCS.get("stop"), Some("Stop the engine."));
+/// assert_eq!(Dispatcher::METHOD_DOCS.get("fly"), None);
+/// assert_eq!(
+///     Dispatcher::METHOD_DOCS.names().collect::<Vec<_>>(),
+///     ["COUNT", "run", "stop"]
+/// );
+/// ```
+///
+/// # Configuration
+///
+/// With the `customise` feature enabled, you can customise this macro's
+/// behaviour using attribute arguments on the `impl` block, and using the
+/// `#[documented_impl(...)]` attribute on individual items.
+///
+/// Multiple option can be specified in a list:
+/// `#[documented_impl(rename_all = "kebab-case", trim = false)]`.
+///
+/// If there are other configuration options you wish to have, please submit an
+/// issue or a PR.
+///
+/// ## 1. set a custom visibility for `METHOD_DOCS`:
+///
+/// ```rust
+/// # #[cfg(feature = "customise")]
+/// mod submodule {
+///     # use documented::documented_impl;
+///     pub struct Dispatcher;
+///
+///     #[documented_impl(vis = pub)]
+///     impl Dispatcher {
+///         /// Start the engine.
+///         fn run(&self) {}
+///     }
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// assert_eq!(
+///     submodule::Dispatcher::METHOD_DOCS.get("run"),
+///     Some("Start the engine.")
+/// );
+/// ```
+///
+/// By default, `METHOD_DOCS` is private.
+///
+/// ## 2. (selectively) rename the keys of `METHOD_DOCS`:
+///
+/// ```rust
+/// # use documented::documented_impl;
+/// struct Dispatcher;
+///
+/// # #[cfg(feature = "customise")]
+/// #[documented_impl(rename_all = "kebab-case")]
+/// impl Dispatcher {
+///     /// Start the engine.
+///     fn start_engine(&self) {}
+///
+///     /// Stop the engine.
+///     #[documented_impl(rename = "halt")]
+///     fn stop_engine(&self) {}
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// {
+/// assert_eq!(Dispatcher::METHOD_DOCS.get("start-engine"), Some("Start the engine."));
+/// assert_eq!(Dispatcher::METHOD_DOCS.get("halt"), Some("Stop the engine."));
+/// // the names of the constants are not affected
+/// assert_eq!(Dispatcher::STOP_ENGINE_DOCS, "Stop the engine.");
+/// }
+/// ```
+///
+/// `rename` always takes priority over `rename_all`.
+///
+/// ## 3. rename `METHOD_DOCS` itself:
+///
+/// On the `impl` block, `rename` sets the name of the generated map instead.
+/// This is needed when a type has more than one `#[documented_impl]` block,
+/// e.g. an inherent `impl` and a trait `impl`.
+///
+/// ```rust
+/// # use documented::documented_impl;
+/// struct Dispatcher;
+///
+/// trait Engine {
+///     fn run(&self);
+/// }
+///
+/// #[documented_impl]
+/// impl Dispatcher {
+///     /// Stop the engine.
+///     fn stop(&self) {}
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// #[documented_impl(rename = "ENGINE_DOCS")]
+/// impl Engine for Dispatcher {
+///     /// Start the engine.
+///     fn run(&self) {}
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// {
+/// assert_eq!(Dispatcher::METHOD_DOCS.get("stop"), Some("Stop the engine."));
+/// assert_eq!(Dispatcher::ENGINE_DOCS.get("run"), Some("Start the engine."));
+/// }
+/// ```
+///
+/// ## 4. (selectively) set a default value when doc comments are absent:
+///
+/// ```rust
+/// # use documented::documented_impl;
+/// struct Dispatcher;
+///
+/// # #[cfg(feature = "customise")]
+/// #[documented_impl(default = "Does something.")]
+/// impl Dispatcher {
+///     fn run(&self) {}
+///
+///     #[documented_impl(default = "Does nothing.")]
+///     fn idle(&self) {}
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// {
+/// assert_eq!(Dispatcher::RUN_DOCS, "Does something.");
+/// assert_eq!(Dispatcher::IDLE_DOCS, "Does nothing.");
+/// }
+/// ```
+///
+/// ## 5. (selectively) disable line-trimming:
+///
+/// ```rust
+/// # use documented::documented_impl;
+/// struct Dispatcher;
+///
+/// # #[cfg(feature = "customise")]
+/// #[documented_impl(trim = false)]
+/// impl Dispatcher {
+///     ///     Start the engine.
+///     fn run(&self) {}
+///
+///     ///     Stop the engine.
+///     #[documented_impl(trim = true)]
+///     fn stop(&self) {}
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// {
+/// assert_eq!(Dispatcher::RUN_DOCS, "     Start the engine.");
+/// assert_eq!(Dispatcher::STOP_DOCS, "Stop the engine.");
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn documented_impl(
+    #[allow(unused_variables)] attr: TokenStream,
+    item: TokenStream,
+) -> TokenStream {
+    #[cfg(not(feature = "customise"))]
+    let ts = documented_impl_impl(parse_macro_input!(item));
+    #[cfg(feature = "customise")]
+    let ts = documented_impl_impl(parse_macro_input!(item), parse_macro_input!(attr));
+
+    ts.unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Macro to extract the documentation on a trait definition and each of its
+/// methods, associated constants and associated types.
+///
+/// If the trait is dyn-compatible, this implements [`Documented`] for the trait
+/// object type `dyn Trait`. Regardless, it generates a `documented::DocsMap`
+/// constant named `<TRAIT>_ITEM_DOCS` that allows looking up the docs of all
+/// items by name. By default, this constant inherits visibility from the trait.
+///
+/// Dyn-compatibility is determined conservatively from the trait definition:
+/// traits with associated types or constants, supertraits other than lifetimes
+/// and auto traits like `Send` and `Sync`, or methods that are generic, have no
+/// receiver or mention `Self` in their signature (unless they require
+/// `Self: Sized`) do not get the `dyn Trait` impl.
+///
+/// # Examples
+///
+/// ```rust
+/// use documented::{documented_trait, Documented};
+///
+/// /// Extends the host with new tricks.
+/// #[documented_trait]
+/// trait Plugin {
+///     /// The name of the plugin.
+///     fn name(&self) -> &'static str;
+///
+///     /// Called when the plugin is loaded.
+///     fn on_load(&mut self) {}
+/// }
+///
+/// assert_eq!(<dyn Plugin as Documented>::DOCS, "Extends the host with new tricks.");
+/// assert_eq!(PLUGIN_ITEM_DOCS.get("name"), Some("The name of the plugin."));
+/// assert_eq!(
+///     PLUGIN_ITEM_DOCS.get("on_load"),
+///     Some("Called when the plugin is loaded.")
+/// );
+/// ```
+///
+/// # Configuration
+///
+/// With the `customise` feature enabled, you can customise this macro's
+/// behaviour using attribute arguments on the trait, and using the
+/// `#[documented_trait(...)]` attribute on individual items.
+///
+/// The options `vis`, `rename_all`, `rename`, `default` and `trim` work the same
+/// as they do for [`macro@documented_impl`]. Additionally:
+///
+/// ## 1. force or disable the implementation for `dyn Trait`:
+///
+/// `dyn_impl = false` never generates the implementation, which also means
+/// the trait itself needs no doc comments. `dyn_impl = true` always generates
+/// it, even if the trait is not detected as dyn-compatible.
+///
+/// ```rust
+/// # use documented::documented_trait;
+/// # #[cfg(feature = "customise")]
+/// #[documented_trait(dyn_impl = false)]
+/// trait Shape {
+///     /// The area of the shape.
+///     fn area(&self) -> f64;
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// assert_eq!(SHAPE_ITEM_DOCS.get("area"), Some("The area of the shape."));
+/// ```
+#[proc_macro_attribute]
+pub fn documented_trait(
+    #[allow(unused_variables)] attr: TokenStream,
+    item: TokenStream,
+) -> TokenStream {
+    #[cfg(not(feature = "customise"))]
+    let ts = documented_trait_impl(parse_macro_input!(item));
+    #[cfg(feature = "customise")]
+    let ts = documented_trait_impl(parse_macro_input!(item), parse_macro_input!(attr));
+
+    ts.unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Macro to extract the documentation of all items in an inline module.
+///
+/// This generates a `documented::DocsMap` constant named `MODULE_DOCS` inside
+/// the module, that allows looking up the docs of its items by name. By
+/// default, this constant is `pub`.
+///
+/// All kinds of items supported by [`macro@docs_const`] are included; other
+/// items (e.g. `impl` blocks and `use` declarations) are skipped, as are items
+/// without doc comments.
+///
+/// # Examples
+///
+/// ```rust
+/// use documented::documented_module;
+///
+/// #[documented_module]
+/// mod shapes {
+///     /// A round shape.
+///     pub struct Circle;
+///
+///     /// Compute the area of a circle.
+///     pub fn area(radius: f64) -> f64 {
+///         std::f64::consts::PI * radius * radius
+///     }
+///
+///     impl Circle {}
+/// }
+///
+/// assert_eq!(shapes::MODULE_DOCS.get("Circle"), Some("A round shape."));
+/// assert_eq!(
+///     shapes::MODULE_DOCS.get("area"),
+///     Some("Compute the area of a circle.")
+/// );
+/// assert_eq!(shapes::MODULE_DOCS.len(), 2);
+/// ```
+///
+/// # Configuration
+///
+/// With the `customise` feature enabled, you can customise this macro's
+/// behaviour using attribute arguments.
+///
+/// The options `vis`, `default` and `trim` work the same as they do for
+/// [`macro@docs_const`], except that `default` is used for all undocumented
+/// items. Additionally:
+///
+/// ## 1. skip private items:
+///
+/// ```rust
+/// # use documented::documented_module;
+/// # #[cfg(feature = "customise")]
+/// #[documented_module(skip_private)]
+/// mod shapes {
+///     /// A round shape.
+///     pub struct Circle;
+///
+///     /// An implementation detail.
+///     struct Cache;
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// {
+/// assert!(shapes::MODULE_DOCS.contains("Circle"));
+/// assert!(!shapes::MODULE_DOCS.contains("Cache"));
+/// }
+/// ```
+///
+/// ## 2. recurse into nested inline modules:
+///
+/// Items in nested modules are keyed by their path relative to the annotated
+/// module.
+///
+/// ```rust
+/// # use documented::documented_module;
+/// # #[cfg(feature = "customise")]
+/// #[documented_module(recursive)]
+/// mod shapes {
+///     /// Shapes with corners.
+///     pub mod polygons {
+///         /// A shape with three corners.
+///         pub struct Triangle;
+///     }
+/// }
+///
+/// # #[cfg(feature = "customise")]
+/// {
+/// assert_eq!(
+///     shapes::MODULE_DOCS.get("polygons"),
+///     Some("Shapes with corners.")
+/// );
+/// assert_eq!(
+///     shapes::MODULE_DOCS.get("polygons::Triangle"),
+///     Some("A shape with three corners.")
+/// );
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn documented_module(
+    #[allow(unused_variables)] attr: TokenStream,
+    item: TokenStream,
+) -> TokenStream {
+    #[cfg(not(feature = "customise"))]
+    let ts = documented_module_impl(parse_macro_input!(item));
+    #[cfg(feature = "customise")]
+    let ts = documented_module_impl(parse_macro_input!(item), parse_macro_input!(attr));
+
+    ts.unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Macro to derive the traits of this crate on every type in an inline module.
+///
+/// The traits to derive are given as arguments, and can be any of
+/// [`Documented`], [`DocumentedFields`] and [`DocumentedVariants`], or their
+/// `Opt` flavours. `DocumentedVariants` is only derived on enums.
+///
+/// The derives are added to every struct, enum and union directly within the
+/// module; all of their configuration options work as usual. Traits that a
+/// type already derives manually are not derived again.
+///
+/// # Examples
+///
+/// ```rust
+/// #[documented::auto(Documented, DocumentedFieldsOpt, DocumentedVariants)]
+/// mod model {
+///     /// A user of the site.
+///     pub struct User {
+///         /// The user's handle.
+///         pub name: String,
+///         pub age: u8,
+///     }
+///
+///     /// The role of a user.
+///     pub enum Role {
+///         /// Can do anything.
+///         Admin,
+///         /// Can do some things.
+///         Member,
+///     }
+/// }
+///
+/// use documented::{Documented, DocumentedFieldsOpt, DocumentedVariants};
+/// use model::{Role, User};
+///
+/// assert_eq!(User::DOCS, "A user of the site.");
+/// assert_eq!(User::get_field_docs("name"), Ok("The user's handle."));
+/// assert!(User::get_field_docs("age").is_err());
+/// assert_eq!(Role::Admin.get_variant_docs(), "Can do anything.");
+/// ```
+///
+/// # Per-type options
+///
+/// Individual types can be configured using the `#[documented_auto(...)]`
+/// attribute.
+///
+/// ## 1. opt out of all or some of the derives:
+///
+/// ```rust
+/// #[documented::auto(Documented, DocumentedFields)]
+/// mod model {
+///     /// A user of the site.
+///     #[documented_auto(skip(DocumentedFields))]
+///     pub struct User {
+///         pub name: String,
+///     }
+///
+///     #[documented_auto(skip)]
+///     pub struct Internal;
+/// }
+///
+/// use documented::Documented;
+///
+/// assert_eq!(model::User::DOCS, "A user of the site.");
+/// ```
+///
+/// ## 2. use the `Opt` flavours of all derives:
+///
+/// ```rust
+/// #[documented::auto(Documented, DocumentedFields)]
+/// mod model {
+///     #[documented_auto(opt)]
+///     pub struct Draft {
+///         pub title: String,
+///     }
+/// }
+///
+/// use documented::{DocumentedFieldsOpt, DocumentedOpt};
+/// use model::Draft;
+///
+/// assert_eq!(Draft::DOCS, None);
+/// assert_eq!(Draft::FIELD_DOCS, [None]);
+/// ```
+#[proc_macro_attribute]
+pub fn auto(attr: TokenStream, item: TokenStream) -> TokenStream {
+    auto_impl(parse_macro_input!(item), parse_macro_input!(attr))
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Macro to extract the inner documentation (i.e. `//!` comments) of the crate
+/// as a string literal.
+///
+/// The crate root is located by convention relative to `CARGO_MANIFEST_DIR`:
+/// when compiling a binary, this is `src/main.rs`, `src/bin/<NAME>.rs` or
+/// `src/bin/<NAME>/main.rs`; otherwise (or if none of those exist), this is
+/// `src/lib.rs`, or `src/main.rs` if the former does not exist. Custom target
+/// paths set in `Cargo.toml` are not taken into account; use
+/// [`module_docs!`] for those.
+///
+/// The docs are processed the same way as the other macros of this crate do,
+/// including line-trimming and the evaluation of built-in macros like
+/// `include_str!`.
+///
+/// # Examples
+///
+/// ```rust
+/// // in `src/lib.rs`:
+/// // //! Derive, attribute and function-like macros for `documented`.
+/// // //!
+/// // //! This crate is not meant to be used directly; use `documented` instead.
+///
+/// const ABOUT: &str = documented::crate_docs!();
+///
+/// assert!(ABOUT.starts_with("Derive, attribute and function-like macros"));
+/// ```
+///
+/// # Configuration
+///
+/// With the `customise` feature enabled, you can customise this macro's
+/// behaviour using arguments.
+///
+/// The options `default` and `trim` work the same as they do for
+/// [`macro@docs_const`]. For example:
+///
+/// ```rust
+/// # #[cfg(feature = "customise")]
+/// const ABOUT: &str = documented::crate_docs!(trim = false);
+///
+/// # #[cfg(feature = "customise")]
+/// assert!(ABOUT.starts_with(" Derive, attribute and function-like macros"));
+/// ```
+#[proc_macro]
+pub fn crate_docs(input: TokenStream) -> TokenStream {
+    crate_docs_impl(parse_macro_input!(input))
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Macro to extract the inner documentation (i.e. `//!` comments) of a source
+/// file as a string literal.
+///
+/// This is useful for module files, whose own doc comments cannot be accessed
+/// using [`macro@docs_const`] because inner attributes cannot be procedural
+/// macros. The path is relative to `CARGO_MANIFEST_DIR`.
+///
+/// Otherwise, this macro works the same as [`crate_docs!`], and accepts the same
+/// options.
+///
+/// # Examples
+///
+/// ```rust
+/// // in `src/attr_impl.rs`:
+/// // //! Implementation of the attribute macros.
+///
+/// const ATTR_IMPL_DOCS: &str = documented::module_docs!("src/attr_impl.rs");
+///
+/// assert_eq!(ATTR_IMPL_DOCS, "Implementation of the attribute macros.");
+/// ```
+#[proc_macro]
+pub fn module_docs(input: TokenStream) -> TokenStream {
+    module_docs_impl(parse_macro_input!(input))
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Macro to get the documentation of a field or variant, checked at compile
+/// time.
+///
+/// This works on types that derive [`DocumentedFields`], and expands to the
+/// same `&'static str` that `get_field_docs` would return. Unlike
+/// `get_field_docs` however, a missing field or variant is a compile error,
+/// and the result can be used in `const` items.
+///
+/// [`DocumentedFieldsOpt`] does not support this, so that both derives can be
+/// used on the same type.
+///
+/// Fields and variants are specified by their name in Rust, ignoring any
+/// renaming. Fields of tuple structs are specified by their index.
+///
+/// # Examples
+///
+/// ```rust
+/// use documented::{docs_of, DocumentedFields};
+///
+/// #[derive(DocumentedFields)]
+/// struct Config {
+///     /// The port to listen on.
+///     port: u16,
+/// }
+///
+/// #[derive(DocumentedFields)]
+/// enum Mode {
+///     /// Go fast.
+///     Fast,
+/// }
+///
+/// #[derive(DocumentedFields)]
+/// struct Pair(
+///     /// The first one.
+///     u8,
+///     /// The second one.
+///     u8,
+/// );
+///
+/// const PORT_DOCS: &str = docs_of!(Config::port);
+///
+/// assert_eq!(PORT_DOCS, "The port to listen on.");
+/// assert_eq!(docs_of!(Mode::Fast), "Go fast.");
+/// assert_eq!(docs_of!(Pair::1), "The second one.");
+/// ```
+///
+/// ```rust,compile_fail
+/// # use documented::{docs_of, DocumentedFields};
+/// #[derive(DocumentedFields)]
+/// struct Config {
+///     /// The port to listen on.
+///     port: u16,
+/// }
+///
+/// // typo
+/// let docs = docs_of!(Config::prot);
+/// ```
+///
+/// The fields of generic types require the generic arguments to be specified:
+///
+/// ```rust
+/// # use documented::{docs_of, DocumentedFields};
+/// #[derive(DocumentedFields)]
+/// struct Wrapper<T> {
+///     /// The wrapped value.
+///     inner: T,
+/// }
+///
+/// assert_eq!(docs_of!(Wrapper::<()>::inner), "The wrapped value.");
+/// ```
+#[proc_macro]
+pub fn docs_of(input: TokenStream) -> TokenStream {
+    docs_of_impl(parse_macro_input!(input))
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
@@ -1,14 +1,126 @@
-use proc_macro2::TokenStream;
-use quote::{quote, ToTokens, TokenStreamExt};
+use std::{
+    cmp::Ordering,
+    env, fs,
+    path::{Path as FsPath, PathBuf},
+};
+
+use convert_case::Casing;
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use syn::{
-    parse_quote, spanned::Spanned, Attribute, Error, Expr, ExprLit, ExprMacro, Item, Lit, Macro,
-    Meta, Path, Visibility,
+    parse::ParseStream, parse_quote, punctuated::Punctuated, spanned::Spanned, Attribute, Error,
+    Expr, ExprLit, ExprMacro, Ident, Item, Lit, LitStr, Macro, Meta, Path, Token, Visibility,
 };
 
+use crate::config::derive_fields::RenameMode;
+
 pub fn crate_module_path() -> Path {
     parse_quote!(::documented)
 }
 
+/// The backends available for looking up names in the generated code.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum LookupMode {
+    /// `phf` if the `phf` feature of `documented` is enabled, otherwise
+    /// `match`.
+    ///
+    /// This is decided by `documented` rather than here, since that is where
+    /// the feature is.
+    #[default]
+    Auto,
+    /// A perfect hash map, via `phf`.
+    #[cfg_attr(not(feature = "customise"), allow(dead_code))]
+    Phf,
+    /// A `match` on the name.
+    #[cfg_attr(not(feature = "customise"), allow(dead_code))]
+    Match,
+    /// A binary search over the names, sorted ahead of time.
+    #[cfg_attr(not(feature = "customise"), allow(dead_code))]
+    BinarySearch,
+}
+
+/// Generate an expression that looks up the index associated with `key`,
+/// evaluating to `Option<usize>`.
+///
+/// `key` must be an expression of type `&str`.
+pub fn lookup_index_tokens<'a, K>(
+    entries: impl IntoIterator<Item = (&'a str, usize)>,
+    key: K,
+    mode: LookupMode,
+) -> TokenStream
+where
+    K: ToTokens,
+{
+    let mut entries = entries.into_iter().collect::<Vec<_>>();
+
+    // all backends rely on names being unique
+    let mut sorted_names = entries.iter().map(|&(name, _)| name).collect::<Vec<_>>();
+    sorted_names.sort_unstable();
+    if let Some(name) = sorted_names
+        .windows(2)
+        .find_map(|w| (w[0] == w[1]).then_some(w[0]))
+    {
+        let msg = format!("Multiple fields or items are named `{name}`");
+        return quote! { compile_error!(#msg) };
+    }
+
+    let documented_module_path = crate_module_path();
+    let arms = entries.iter().map(|(name, i)| quote! { #name => #i });
+    match mode {
+        LookupMode::Auto => quote! {
+            #documented_module_path::_private_lookup_auto!(#key; #(#arms),*)
+        },
+        LookupMode::Phf => quote! {
+            #documented_module_path::_private_lookup_phf!(#key; #(#arms),*)
+        },
+        LookupMode::Match => {
+            let match_arms = entries
+                .iter()
+                .map(|(name, i)| quote! { #name => Some(#i), });
+            quote! {
+                match #key {
+                    #(#match_arms)*
+                    _ => None,
+                }
+            }
+        }
+        LookupMode::BinarySearch => {
+            entries.sort_unstable_by_key(|&(name, _)| name);
+            let len = entries.len();
+            let sorted_entries = entries.iter().map(|(name, i)| quote! { (#name, #i) });
+            quote! {{
+                static SORTED: [(&str, usize); #len] = [#(#sorted_entries),*];
+                let key: &str = #key;
+                SORTED
+                    .binary_search_by(|&(name, _)| name.cmp(key))
+                    .ok()
+                    .map(|i| SORTED[i].1)
+            }}
+        }
+    }
+}
+
+/// Generate an expression that evaluates to a `DocsMap` with the given names
+/// and doc expressions, in order.
+pub fn docs_map_tokens(entries: &[(String, TokenStream)]) -> TokenStream {
+    let documented_module_path = crate_module_path();
+    let lookup = lookup_index_tokens(
+        entries
+            .iter()
+            .enumerate()
+            .map(|(i, (name, _))| (name.as_str(), i)),
+        quote! { name },
+        LookupMode::default(),
+    );
+    let (names, docs): (Vec<_>, Vec<_>) = entries.iter().cloned().unzip();
+    quote! {{
+        fn index_of(name: &str) -> Option<usize> {
+            #lookup
+        }
+        #documented_module_path::DocsMap::__new(&[#((#names, #docs)),*], index_of)
+    }}
+}
+
 pub fn get_vis_name_attrs(item: &Item) -> syn::Result<(Visibility, String, &[Attribute])> {
     match item {
         Item::Const(item) => Ok((item.vis.clone(), item.ident.to_string(), &item.attrs)),
@@ -47,20 +159,50 @@ pub fn get_vis_name_attrs(item: &Item) -> syn::Result<(Visibility, String, &[Att
     }
 }
 
+/// Get the identifier of the hidden associated constant holding the docs of a
+/// field or variant, given its name as written in Rust (or its index).
+pub fn member_docs_const_ident(member: &str, span: Span) -> Ident {
+    Ident::new(&format!("__documented_docs_{member}"), span)
+}
+
+/// Get the name of a field, variant or item as accepted by the generated
+/// lookups, with renaming applied.
+pub fn get_name(ident: Option<&Ident>, rename_mode: Option<RenameMode>) -> Option<String> {
+    match rename_mode {
+        None => ident.map(|ident| ident.to_string()),
+        Some(RenameMode::ToCase(case)) => ident.map(|ident| ident.to_string().to_case(case)),
+        Some(RenameMode::Custom(name)) => Some(name),
+    }
+}
+
+/// How to trim the lines of doc comments.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TrimMode {
+    /// Keep doc comments as-is.
+    #[cfg_attr(not(feature = "customise"), allow(dead_code))]
+    None,
+    /// Trim whitespace off both ends of every line.
+    All,
+    /// Remove the common leading indentation, the ` * ` prefixes of `/** */`
+    /// blocks, and leading and trailing blank lines, like rustdoc does.
+    Dedent,
+}
+
 /// The processed value(s) of `#[doc = VAL]` attribute(s).
 #[derive(Clone, Debug)]
-enum DocValue<'a> {
-    /// At least one consecutive `/// foo` or `#{doc = "foo"]`.
+enum DocValue {
+    /// At least one consecutive `/// foo`, `#{doc = "foo"]`, or invocation of
+    /// a built-in macro that could be evaluated (see [`eval_macro`]).
     ///
     /// - Each literal value is trimmed if requested.
     /// - Consecutive literal values are folded into one.
     Lit(String),
-    /// `#[doc = include_str!("path")]`.
+    /// `#[doc = some_macro!(...)]` that could not be evaluated.
     ///
     /// No processing on this form because we don't have the expansion.
-    Macro(&'a Macro),
+    Macro(Macro),
 }
-impl ToTokens for DocValue<'_> {
+impl ToTokens for DocValue {
     fn to_tokens(&self, ts: &mut TokenStream) {
         let tokens = match self {
             Self::Lit(lit) => quote! { #lit },
@@ -70,60 +212,492 @@ impl ToTokens for DocValue<'_> {
     }
 }
 
+/// A single `#[doc = VAL]` attribute, before aggregation.
+#[derive(Clone, Debug)]
+struct DocAttr {
+    value: DocValue,
+    /// Whether this is a doc comment, as opposed to `#[doc = VAL]`.
+    is_sugared: bool,
+    /// The predicate of the `#[cfg_attr(PRED, ...)]` this attribute is in.
+    cfg: Option<Meta>,
+}
+
 /// The processed and aggregated values of `#[doc = VAL]` attribute(s).
 #[derive(Clone, Debug)]
-pub struct DocContent<'a>(Vec<DocValue<'a>>);
-impl ToTokens for DocContent<'_> {
+pub struct DocContent {
+    /// Each value is paired with the `cfg` predicate it depends on.
+    values: Vec<(Option<Meta>, DocValue)>,
+    /// The macros that were evaluated by us.
+    ///
+    /// They are still emitted (and discarded) so that the compiler knows about
+    /// the files and environment variables they depend on.
+    evaluated: Vec<Macro>,
+    /// The files read by `include_str!` in the evaluated macros.
+    included: Vec<PathBuf>,
+}
+impl ToTokens for DocContent {
     fn to_tokens(&self, ts: &mut TokenStream) {
-        let tokens = match self.0.as_slice() {
-            [] => unreachable!("0-length DocContent should not be produced"),
-            [single] => quote! { #single },
-            [head, tail @ ..] => quote! { concat!(#head, #("\n", #tail),*) },
+        let content = if self.values.iter().any(|(cfg, _)| cfg.is_some()) {
+            self.cfg_content()
+        } else {
+            let values = self.values.iter().map(|(_, val)| val);
+            match values.collect::<Vec<_>>().as_slice() {
+                [] => unreachable!("0-length DocContent should not be produced"),
+                [single] => quote! { #single },
+                [head, tail @ ..] => quote! { concat!(#head, #("\n", #tail),*) },
+            }
+        };
+        let evaluated = &self.evaluated;
+        let tokens = if evaluated.is_empty() {
+            content
+        } else {
+            quote! {{
+                #(const _: &str = #evaluated;)*
+                #content
+            }}
         };
         ts.append_all([tokens]);
     }
 }
+impl DocContent {
+    /// Get the docs as a string, if they are fully known at macro time, i.e.
+    /// they contain no unevaluated macros and no `cfg`-conditional parts.
+    pub fn as_known_str(&self) -> Option<&str> {
+        match self.values.as_slice() {
+            [(None, DocValue::Lit(lit))] => Some(lit),
+            _ => None,
+        }
+    }
 
-pub fn get_docs(attrs: &[Attribute], trim: bool) -> syn::Result<Option<DocContent<'_>>> {
-    let content = attrs
-        .iter()
-        .filter_map(|attr| match attr.meta {
-            Meta::NameValue(ref name_value) if name_value.path.is_ident("doc") => {
-                Some(&name_value.value)
+    /// Emit an `include_str!` of each file the docs were read from, by
+    /// absolute path, so that the compiler tracks them even when the evaluated
+    /// macros themselves cannot be emitted, i.e. when they come from another
+    /// file.
+    pub fn included_files(&self) -> TokenStream {
+        let paths = self.included.iter().map(|path| path.to_string_lossy());
+        quote! { #(const _: &str = include_str!(#paths);)* }
+    }
+
+    /// Generate the docs as a `concat!` of pieces, some of which are defined
+    /// conditionally by `cfg`'d local macros.
+    fn cfg_content(&self) -> TokenStream {
+        // pieces before the first unconditional one carry their separator at
+        // the end, so that no stray newline is left when they are cfg'd out
+        let anchor = self
+            .values
+            .iter()
+            .position(|(cfg, _)| cfg.is_none())
+            .unwrap_or(self.values.len() - 1);
+
+        let mut defs = vec![];
+        let mut pieces = vec![];
+        for (i, (cfg, val)) in self.values.iter().enumerate() {
+            let piece = match i.cmp(&anchor) {
+                Ordering::Less => quote! { #val, "\n" },
+                Ordering::Equal => quote! { #val },
+                Ordering::Greater => quote! { "\n", #val },
+            };
+            match cfg {
+                None => pieces.push(piece),
+                Some(pred) => {
+                    let name = format_ident!("__documented_cfg_piece_{i}");
+                    defs.push(quote! {
+                        #[cfg(#pred)]
+                        macro_rules! #name { () => { concat!(#piece) } }
+                        #[cfg(not(#pred))]
+                        macro_rules! #name { () => { "" } }
+                    });
+                    pieces.push(quote! { #name!() });
+                }
             }
-            _ => None,
+        }
+
+        quote! {{
+            #(#defs)*
+            concat!(#(#pieces),*)
+        }}
+    }
+}
+
+pub fn get_docs(attrs: &[Attribute], trim: TrimMode) -> syn::Result<Option<DocContent>> {
+    get_docs_impl(attrs, trim, None)
+}
+
+/// Like [`get_docs`], but for attributes parsed from the source file in
+/// `file_dir`, instead of macro input.
+///
+/// `include_str!` is resolved relative to `file_dir`. The source code of such
+/// attributes is unavailable, so literal docs are assumed to be doc comments,
+/// which is by far the most common form of inner docs.
+pub fn get_file_docs(
+    attrs: &[Attribute],
+    trim: TrimMode,
+    file_dir: &FsPath,
+) -> syn::Result<Option<DocContent>> {
+    get_docs_impl(attrs, trim, Some(file_dir))
+}
+
+fn get_docs_impl(
+    attrs: &[Attribute],
+    trim: TrimMode,
+    file_dir: Option<&FsPath>,
+) -> syn::Result<Option<DocContent>> {
+    let mut evaluated = vec![];
+    let mut included = vec![];
+    let mut values = collect_doc_attrs(attrs)?
+        .into_iter()
+        .map(|(attr, cfg, expr)| match expr {
+            Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) => {
+                // doc comments are indistinguishable from `#[doc = "..."]` once
+                // parsed, so look at the source code instead if available
+                let is_sugared = file_dir.is_some()
+                    || attr
+                        .span()
+                        .source_text()
+                        .is_some_and(|src| src.starts_with("///") || src.starts_with("/**"));
+                Ok(DocAttr {
+                    value: DocValue::Lit(lit.value()),
+                    is_sugared,
+                    cfg,
+                })
+            }
+            Expr::Macro(ExprMacro { mac, .. }) => {
+                let value = match eval_macro(&mac, file_dir, &mut included) {
+                    Some(value) => {
+                        evaluated.push(mac);
+                        DocValue::Lit(value)
+                    }
+                    None => DocValue::Macro(mac),
+                };
+                Ok(DocAttr { value, is_sugared: false, cfg })
+            }
+            other => Err(Error::new(
+                other.span(),
+                "Doc comment is neither a string literal nor a macro invocation",
+            )),
         })
-        .try_fold(None, |docs: Option<Vec<_>>, expr| -> syn::Result<_> {
-            let val = match expr {
-                Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) => {
-                    let maybe_trimmed = if trim {
-                        lit.value()
-                            .split('\n')
-                            .map(|line| line.trim().to_string())
-                            .collect::<Vec<_>>()
-                            .join("\n")
-                    } else {
-                        lit.value()
-                    };
-                    DocValue::Lit(maybe_trimmed)
+        .collect::<syn::Result<Vec<_>>>()?;
+    if values.is_empty() {
+        return Ok(None);
+    }
+
+    match trim {
+        TrimMode::None => {}
+        TrimMode::All => {
+            for attr in values.iter_mut() {
+                if let DocValue::Lit(lit) = &mut attr.value {
+                    *lit = lit
+                        .split('\n')
+                        .map(str::trim)
+                        .collect::<Vec<_>>()
+                        .join("\n");
                 }
-                Expr::Macro(ExprMacro { mac, .. }) => DocValue::Macro(mac),
-                other => Err(Error::new(
-                    other.span(),
-                    "Doc comment is neither a string literal nor a macro invocation",
-                ))?,
-            };
+            }
+        }
+        TrimMode::Dedent => dedent(&mut values),
+    }
+
+    let mut docs = Vec::<(Option<Meta>, DocValue)>::new();
+    for DocAttr { value, cfg, .. } in values {
+        if let ([.., (None, DocValue::Lit(tail))], (None, DocValue::Lit(lit))) =
+            (docs.as_mut_slice(), (&cfg, &value))
+        {
+            // fold consecutive unconditional literal forms
+            tail.push('\n');
+            tail.push_str(lit);
+        } else {
+            docs.push((cfg, value));
+        }
+    }
 
-            let mut docs = docs.unwrap_or_default();
-            if let ([.., DocValue::Lit(tail)], DocValue::Lit(lit)) = (docs.as_mut_slice(), &val) {
-                // fold consecutive literal forms
-                tail.push('\n');
-                tail.push_str(lit);
-            } else {
-                docs.push(val);
+    if trim == TrimMode::Dedent {
+        // drop leading and trailing blank lines, which are empty after dedenting
+        if let Some((_, DocValue::Lit(head))) = docs.first_mut() {
+            *head = head.trim_start_matches('\n').to_string();
+        }
+        if let Some((_, DocValue::Lit(tail))) = docs.last_mut() {
+            *tail = tail.trim_end_matches('\n').to_string();
+        }
+    }
+
+    Ok(Some(DocContent { values: docs, evaluated, included }))
+}
+
+/// Collect the values of all `#[doc = VAL]` attributes, including those in
+/// (possibly nested) `#[cfg_attr(PRED, doc = VAL, ...)]`, together with the
+/// attribute they come from and the predicate they depend on.
+fn collect_doc_attrs(attrs: &[Attribute]) -> syn::Result<Vec<(&Attribute, Option<Meta>, Expr)>> {
+    fn collect_from_meta<'a>(
+        attr: &'a Attribute,
+        cfg: Option<Meta>,
+        meta: &Meta,
+        out: &mut Vec<(&'a Attribute, Option<Meta>, Expr)>,
+    ) -> syn::Result<()> {
+        match meta {
+            Meta::NameValue(name_value) if name_value.path.is_ident("doc") => {
+                out.push((attr, cfg, name_value.value.clone()));
+            }
+            Meta::List(list) if list.path.is_ident("cfg_attr") => {
+                let (pred, metas) = list.parse_args_with(|input: ParseStream| {
+                    let pred = input.parse::<Meta>()?;
+                    input.parse::<Token![,]>()?;
+                    let metas = Punctuated::<Meta, Token![,]>::parse_terminated(input)?;
+                    Ok((pred, metas))
+                })?;
+                let cfg = match cfg {
+                    Some(outer) => parse_quote! { all(#outer, #pred) },
+                    None => pred,
+                };
+                for meta in metas.iter() {
+                    collect_from_meta(attr, Some(cfg.clone()), meta, out)?;
+                }
             }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    let mut out = vec![];
+    for attr in attrs {
+        collect_from_meta(attr, None, &attr.meta, &mut out)?;
+    }
+    Ok(out)
+}
+
+/// The `#[doc(...)]` attributes that matter to us, besides the docs
+/// themselves.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DocMeta {
+    /// Declared with `#[doc(alias = "...")]` or `#[doc(alias("...", ...))]`.
+    pub aliases: Vec<String>,
+    /// Whether `#[doc(hidden)]` is present.
+    pub hidden: bool,
+}
 
-            Ok(Some(docs))
+/// Collect the [`DocMeta`] of an item.
+///
+/// Attributes in `#[cfg_attr(...)]` are not collected, since they may not
+/// apply.
+pub fn get_doc_meta(attrs: &[Attribute]) -> syn::Result<DocMeta> {
+    let mut doc_meta = DocMeta::default();
+    for attr in attrs {
+        let Meta::List(list) = &attr.meta else {
+            continue;
+        };
+        if !list.path.is_ident("doc") {
+            continue;
+        }
+        list.parse_nested_meta(|meta| {
+            let is_alias = meta.path.is_ident("alias");
+            if meta.input.peek(Token![=]) {
+                let value = meta.value()?;
+                if is_alias {
+                    doc_meta.aliases.push(value.parse::<LitStr>()?.value());
+                } else {
+                    // other `#[doc(...)]` attributes are not our concern
+                    value.parse::<Expr>()?;
+                }
+            } else if meta.input.peek(syn::token::Paren) {
+                if is_alias {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let lits = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+                    doc_meta.aliases.extend(lits.iter().map(LitStr::value));
+                } else {
+                    meta.input.parse::<TokenTree>()?;
+                }
+            } else if meta.path.is_ident("hidden") {
+                doc_meta.hidden = true;
+            }
+            Ok(())
         })?;
-    Ok(content.map(DocContent))
+    }
+    Ok(doc_meta)
+}
+
+/// Try to evaluate an invocation of `concat!`, `include_str!` or `env!`, so
+/// that its output can be processed like a literal.
+///
+/// Returns `None` for any other macro, or if the invocation cannot be
+/// evaluated here. In that case, it should be passed through verbatim and left
+/// to the compiler.
+///
+/// The files read along the way are appended to `included`.
+fn eval_macro(
+    mac: &Macro,
+    base_dir: Option<&FsPath>,
+    included: &mut Vec<PathBuf>,
+) -> Option<String> {
+    let segments = mac
+        .path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>();
+    let name = match segments.as_slice() {
+        [name] => name,
+        [krate, name] if krate == "std" || krate == "core" => name,
+        _ => return None,
+    };
+    let args = mac
+        .parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+        .ok()?;
+    let args = args.iter().collect::<Vec<_>>();
+
+    match (name.as_str(), args.as_slice()) {
+        ("concat", args) => args
+            .iter()
+            .map(|arg| eval_concat_arg(arg, base_dir, included))
+            .collect(),
+        ("include_str", [path]) => {
+            let path = eval_str_expr(path, base_dir, included)?;
+            let path = resolve_include_path(&path, mac.path.span(), base_dir)?;
+            let content = fs::read_to_string(&path).ok()?;
+            included.push(path);
+            Some(content)
+        }
+        ("env", [var] | [var, _]) => env::var(eval_str_expr(var, base_dir, included)?).ok(),
+        _ => None,
+    }
+}
+
+/// Evaluate an expression that is expected to be a string, i.e. a string
+/// literal or the invocation of a built-in macro producing one.
+fn eval_str_expr(
+    expr: &Expr,
+    base_dir: Option<&FsPath>,
+    included: &mut Vec<PathBuf>,
+) -> Option<String> {
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) => Some(lit.value()),
+        Expr::Macro(ExprMacro { mac, .. }) => eval_macro(mac, base_dir, included),
+        Expr::Group(group) => eval_str_expr(&group.expr, base_dir, included),
+        _ => None,
+    }
+}
+
+/// Evaluate an argument of `concat!`, which can also be other kinds of
+/// literals.
+fn eval_concat_arg(
+    expr: &Expr,
+    base_dir: Option<&FsPath>,
+    included: &mut Vec<PathBuf>,
+) -> Option<String> {
+    match expr {
+        Expr::Lit(ExprLit { lit, .. }) => match lit {
+            Lit::Str(lit) => Some(lit.value()),
+            Lit::Char(lit) => Some(lit.value().to_string()),
+            Lit::Bool(lit) => Some(lit.value().to_string()),
+            Lit::Int(lit) => Some(lit.base10_digits().to_string()),
+            Lit::Float(lit) => Some(lit.base10_digits().to_string()),
+            _ => None,
+        },
+        Expr::Group(group) => eval_concat_arg(&group.expr, base_dir, included),
+        other => eval_str_expr(other, base_dir, included),
+    }
+}
+
+/// Resolve the path of `include_str!` like the compiler does, i.e. relative to
+/// the file containing the invocation, unless another base directory is given.
+///
+/// If the invoking file is unknown (e.g. on older compilers), the path is
+/// resolved relative to `CARGO_MANIFEST_DIR` instead.
+fn resolve_include_path(path: &str, span: Span, base_dir: Option<&FsPath>) -> Option<PathBuf> {
+    let path = FsPath::new(path);
+    if path.is_absolute() {
+        return Some(path.to_path_buf());
+    }
+    let base_dir = base_dir
+        .map(FsPath::to_path_buf)
+        .or_else(|| {
+            span.local_file()
+                .and_then(|file| file.parent().map(FsPath::to_path_buf))
+        })
+        .or_else(|| env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from))?;
+    Some(base_dir.join(path))
+}
+
+/// Remove the common leading indentation of literal doc values, mimicking
+/// rustdoc.
+///
+/// Each value is accompanied by whether it is a doc comment, as opposed to a
+/// `#[doc = "..."]` attribute. Like rustdoc, when both kinds are mixed, the
+/// latter are treated as if they had one more space of indentation, so that
+/// the conventional space after `///` is removed without eating into the
+/// attributes.
+fn dedent(values: &mut [DocAttr]) {
+    for DocAttr { value, is_sugared, .. } in values.iter_mut() {
+        if let (DocValue::Lit(lit), true) = (value, *is_sugared) {
+            if lit.contains('\n') {
+                *lit = beautify_block_comment(lit);
+            }
+        }
+    }
+
+    let has_sugared = values
+        .iter()
+        .any(|attr| matches!(attr.value, DocValue::Lit(_)) && attr.is_sugared);
+    let has_raw = values
+        .iter()
+        .any(|attr| matches!(attr.value, DocValue::Lit(_)) && !attr.is_sugared);
+    let extra_indent = |is_sugared: bool| usize::from(has_sugared && has_raw && !is_sugared);
+
+    // like rustdoc, only ASCII spaces and tabs count as indentation
+    let indent_of = |line: &str| line.len() - line.trim_start_matches([' ', '\t']).len();
+    let min_indent = values
+        .iter()
+        .filter_map(|attr| match &attr.value {
+            DocValue::Lit(lit) => Some((lit, attr.is_sugared)),
+            DocValue::Macro(_) => None,
+        })
+        .flat_map(|(lit, is_sugared)| {
+            lit.split('\n')
+                .filter(|line| !line.trim().is_empty())
+                .map(move |line| indent_of(line) + extra_indent(is_sugared))
+        })
+        .min()
+        .unwrap_or(0);
+
+    for DocAttr { value, is_sugared, .. } in values.iter_mut() {
+        if let DocValue::Lit(lit) = value {
+            let strip = min_indent.saturating_sub(extra_indent(*is_sugared));
+            *lit = lit
+                .split('\n')
+                .map(|line| {
+                    if line.trim().is_empty() {
+                        ""
+                    } else {
+                        &line[strip.min(indent_of(line))..]
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+        }
+    }
+}
+
+/// Remove the opening and closing blank lines of a `/** */` block, as well as
+/// the ` *` prefix of each line if all lines have one.
+fn beautify_block_comment(lit: &str) -> String {
+    let mut lines = lit.split('\n').collect::<Vec<_>>();
+    if lines.first().is_some_and(|line| line.trim().is_empty()) {
+        lines.remove(0);
+    }
+    if lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    let all_starred = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .all(|line| line.trim_start().starts_with('*'));
+    if all_starred {
+        for line in lines.iter_mut() {
+            if let Some(rest) = line.trim_start().strip_prefix('*') {
+                *line = rest;
+            }
+        }
+    }
+
+    lines.join("\n")
 }
@@ -7,7 +7,22 @@
 //! I.e. the `compile_fail` examples fail, but it's due to other errors
 //! that we are not trying to test for.
 
+#[cfg(test)]
+mod auto;
 #[cfg(test)]
 mod docs_const;
+#[cfg(test)]
+mod documented_impl;
+#[cfg(test)]
+mod documented_module;
+#[cfg(test)]
+mod documented_trait;
+#[cfg(test)]
+mod param_docs;
 
+mod fail_auto;
 mod fail_docs_const;
+mod fail_documented_impl;
+mod fail_documented_module;
+mod fail_documented_trait;
+mod fail_param_docs;
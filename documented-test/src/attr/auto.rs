@@ -0,0 +1,153 @@
+use documented::{
+    Documented, DocumentedFields, DocumentedFieldsOpt, DocumentedOpt, DocumentedVariants,
+};
+
+#[documented::auto(Documented, DocumentedFields, DocumentedVariants)]
+#[allow(dead_code)]
+mod model {
+    /// A user.
+    pub struct User {
+        /// The user's handle.
+        pub name: String,
+    }
+
+    /// A role.
+    pub enum Role {
+        /// Can do anything.
+        Admin,
+        /// Can do some things.
+        Member,
+    }
+
+    /// A number or a float.
+    pub union Number {
+        /// An integer.
+        pub int: u32,
+        /// A float.
+        pub float: f32,
+    }
+
+    /// Not a type.
+    pub fn not_a_type() {}
+}
+
+#[test]
+fn it_works() {
+    assert_eq!(model::User::DOCS, "A user.");
+    assert_eq!(model::User::FIELD_NAMES, ["name"]);
+    assert_eq!(
+        model::User::get_field_docs("name"),
+        Ok("The user's handle.")
+    );
+
+    assert_eq!(model::Role::DOCS, "A role.");
+    assert_eq!(model::Role::FIELD_NAMES, ["Admin", "Member"]);
+    assert_eq!(
+        model::Role::Member.get_variant_docs(),
+        "Can do some things."
+    );
+
+    assert_eq!(model::Number::DOCS, "A number or a float.");
+    assert_eq!(model::Number::get_field_docs("float"), Ok("A float."));
+}
+
+#[test]
+fn opt_flavours_work() {
+    #[documented::auto(DocumentedOpt, DocumentedFieldsOpt)]
+    #[allow(dead_code)]
+    mod model {
+        pub struct User {
+            /// The user's handle.
+            pub name: String,
+            pub age: u8,
+        }
+    }
+
+    assert_eq!(model::User::DOCS, None);
+    assert_eq!(model::User::FIELD_DOCS, [Some("The user's handle."), None]);
+}
+
+#[test]
+fn skip_works() {
+    #[documented::auto(Documented, DocumentedFields)]
+    #[allow(dead_code)]
+    mod model {
+        /// A user.
+        #[documented_auto(skip(DocumentedFields))]
+        pub struct User {
+            pub name: String,
+        }
+
+        #[documented_auto(skip)]
+        pub struct Undocumented {
+            pub name: String,
+        }
+    }
+
+    assert_eq!(model::User::DOCS, "A user.");
+}
+
+#[test]
+fn per_type_opt_works() {
+    #[documented::auto(Documented, DocumentedFields)]
+    #[allow(dead_code)]
+    mod model {
+        /// A user.
+        pub struct User {
+            /// The user's handle.
+            pub name: String,
+        }
+
+        #[documented_auto(opt)]
+        pub struct Draft {
+            pub title: String,
+        }
+    }
+
+    assert_eq!(model::User::DOCS, "A user.");
+    assert_eq!(<model::Draft as DocumentedOpt>::DOCS, None);
+    assert_eq!(<model::Draft as DocumentedFieldsOpt>::FIELD_DOCS, [None]);
+}
+
+#[test]
+fn existing_derives_are_kept() {
+    #[documented::auto(Documented, DocumentedFields)]
+    #[allow(dead_code)]
+    mod model {
+        /// A user.
+        #[derive(documented::DocumentedOpt)]
+        pub struct User {
+            /// The user's handle.
+            pub name: String,
+        }
+    }
+
+    assert_eq!(model::User::DOCS, Some("A user."));
+    assert_eq!(
+        model::User::get_field_docs("name"),
+        Ok("The user's handle.")
+    );
+}
+
+#[cfg(feature = "customise")]
+mod test_customise {
+    use documented::{Documented, DocumentedFields};
+
+    #[test]
+    fn helper_attributes_work() {
+        #[documented::auto(Documented, DocumentedFields)]
+        #[allow(dead_code)]
+        mod model {
+            /// A user.
+            #[documented(trim = false)]
+            #[documented_fields(rename_all = "kebab-case")]
+            pub struct User {
+                /// The user's handle.
+                pub user_name: String,
+            }
+        }
+
+        assert_eq!(model::User::DOCS, " A user.");
+        assert_eq!(model::User::FIELD_NAMES, ["user-name"]);
+    }
+}
@@ -25,6 +25,22 @@ fn multiple_docs_work() {
     );
 }
 
+#[test]
+fn extern_block_works() {
+    #[docs_const]
+    #[allow(dead_code)]
+    extern "C" {
+        /// Compute the absolute value.
+        fn abs(input: i32) -> i32;
+
+        /// The last error number.
+        pub static errno: i32;
+    }
+
+    assert_eq!(ABS_DOCS, "Compute the absolute value.");
+    assert_eq!(ERRNO_DOCS, "The last error number.");
+}
+
 #[cfg(feature = "customise")]
 mod test_customise {
     use documented::docs_const;
@@ -53,6 +69,18 @@ mod test_customise {
         assert_eq!(NEVER_PLAY_F6, "Suspicious");
     }
 
+    #[test]
+    fn cfg_attr_works() {
+        /// This is a test function
+        #[docs_const]
+        #[cfg_attr(all(), doc = "on every platform")]
+        #[cfg_attr(any(), doc = "on no platform")]
+        #[allow(dead_code)]
+        fn test_fn() {}
+
+        assert_eq!(TEST_FN_DOCS, "This is a test function\non every platform");
+    }
+
     #[test]
     fn trim_works() {
         ///           This is a test function        
@@ -77,4 +105,38 @@ mod test_customise {
             "           This is a test function        \n        Test Trim"
         ); // The whitespace is preserved, even on the end of the first line
     }
+
+    #[test]
+    fn dedent_works() {
+        ///   This is a test function
+        ///
+        ///       let indented = true;
+        #[docs_const(trim = "dedent")]
+        #[allow(dead_code)]
+        fn test_fn() {}
+
+        assert_eq!(
+            TEST_FN_DOCS,
+            "This is a test function\n\n    let indented = true;"
+        );
+    }
+
+    #[test]
+    fn extern_block_options_work() {
+        mod ffi {
+            use documented::docs_const;
+
+            #[docs_const(vis = pub, default = "Undocumented.", trim = false)]
+            #[allow(dead_code)]
+            extern "C" {
+                ///   Compute the absolute value.
+                fn abs(input: i32) -> i32;
+
+                static errno: i32;
+            }
+        }
+
+        assert_eq!(ffi::ABS_DOCS, "   Compute the absolute value.");
+        assert_eq!(ffi::ERRNO_DOCS, "Undocumented.");
+    }
 }
@@ -0,0 +1,219 @@
+use documented::documented_impl;
+
+#[test]
+fn it_works() {
+    struct Engine;
+
+    #[documented_impl]
+    #[allow(dead_code)]
+    impl Engine {
+        /// The maximum speed.
+        const MAX_SPEED: u8 = 88;
+
+        /// Start the engine.
+        fn start(&self) {}
+
+        /// Stop the engine.
+        fn stop(&self) {}
+    }
+
+    assert_eq!(Engine::MAX_SPEED_DOCS, "The maximum speed.");
+    assert_eq!(Engine::START_DOCS, "Start the engine.");
+    assert_eq!(Engine::STOP_DOCS, "Stop the engine.");
+
+    assert_eq!(Engine::METHOD_DOCS.len(), 3);
+    assert_eq!(Engine::METHOD_DOCS.get("start"), Some("Start the engine."));
+    assert_eq!(
+        Engine::METHOD_DOCS.get("MAX_SPEED"),
+        Some("The maximum speed.")
+    );
+    assert_eq!(Engine::METHOD_DOCS.get("fly"), None);
+    assert_eq!(
+        Engine::METHOD_DOCS.entries(),
+        [
+            ("MAX_SPEED", "The maximum speed."),
+            ("start", "Start the engine."),
+            ("stop", "Stop the engine.")
+        ]
+    );
+}
+
+#[test]
+fn trait_impl_works() {
+    #[allow(dead_code)]
+    trait Vehicle {
+        type Fuel;
+        fn drive(&self);
+    }
+
+    struct Car;
+
+    #[documented_impl]
+    impl Vehicle for Car {
+        /// Dinosaur juice.
+        type Fuel = ();
+
+        /// Vroom.
+        fn drive(&self) {}
+    }
+
+    assert_eq!(Car::FUEL_DOCS, "Dinosaur juice.");
+    assert_eq!(Car::DRIVE_DOCS, "Vroom.");
+    assert_eq!(Car::METHOD_DOCS.get("Fuel"), Some("Dinosaur juice."));
+}
+
+#[test]
+fn generic_impl_works() {
+    struct Wrapper<T>(T);
+
+    #[documented_impl]
+    #[allow(dead_code)]
+    impl<T: Copy> Wrapper<T>
+    where
+        T: Default,
+    {
+        /// Get the inner value.
+        fn get(&self) -> T {
+            self.0
+        }
+    }
+
+    assert_eq!(Wrapper::<u8>::GET_DOCS, "Get the inner value.");
+    assert_eq!(
+        Wrapper::<u8>::METHOD_DOCS.get("get"),
+        Some("Get the inner value.")
+    );
+}
+
+#[test]
+fn raw_ident_works() {
+    struct Keywords;
+
+    #[documented_impl]
+    #[allow(dead_code)]
+    impl Keywords {
+        /// Not a type.
+        fn r#type(&self) {}
+    }
+
+    assert_eq!(Keywords::TYPE_DOCS, "Not a type.");
+    assert_eq!(Keywords::METHOD_DOCS.get("type"), Some("Not a type."));
+}
+
+#[cfg(feature = "customise")]
+mod test_customise {
+    use documented::documented_impl;
+
+    #[test]
+    fn rename_all_and_rename_work() {
+        struct Engine;
+
+        #[documented_impl(rename_all = "kebab-case")]
+        #[allow(dead_code)]
+        impl Engine {
+            /// Start the engine.
+            fn start_engine(&self) {}
+
+            /// Stop the engine.
+            #[documented_impl(rename = "halt")]
+            fn stop_engine(&self) {}
+
+            /// Restart the engine.
+            #[documented_impl(rename_all = "SCREAMING_SNAKE_CASE")]
+            fn restart_engine(&self) {}
+        }
+
+        assert_eq!(
+            Engine::METHOD_DOCS.names().collect::<Vec<_>>(),
+            ["start-engine", "halt", "RESTART_ENGINE"]
+        );
+        assert_eq!(Engine::STOP_ENGINE_DOCS, "Stop the engine.");
+    }
+
+    #[test]
+    fn default_works() {
+        struct Engine;
+
+        #[documented_impl(default = "Undocumented.")]
+        #[allow(dead_code)]
+        impl Engine {
+            fn start(&self) {}
+
+            #[documented_impl(default = "Does nothing.")]
+            fn idle(&self) {}
+
+            /// Stop the engine.
+            fn stop(&self) {}
+        }
+
+        assert_eq!(Engine::START_DOCS, "Undocumented.");
+        assert_eq!(Engine::IDLE_DOCS, "Does nothing.");
+        assert_eq!(Engine::STOP_DOCS, "Stop the engine.");
+    }
+
+    #[test]
+    fn trim_works() {
+        struct Engine;
+
+        #[documented_impl(trim = false)]
+        #[allow(dead_code)]
+        impl Engine {
+            ///   Start the engine.
+            fn start(&self) {}
+
+            ///   Stop the engine.
+            #[documented_impl(trim = true)]
+            fn stop(&self) {}
+        }
+
+        assert_eq!(Engine::START_DOCS, "   Start the engine.");
+        assert_eq!(Engine::STOP_DOCS, "Stop the engine.");
+    }
+
+    #[test]
+    fn custom_visibility_works() {
+        mod engine {
+            use documented::documented_impl;
+
+            pub struct Engine;
+
+            #[documented_impl(vis = pub)]
+            #[allow(dead_code)]
+            impl Engine {
+                /// Start the engine.
+                fn start(&self) {}
+            }
+        }
+
+        assert_eq!(
+            engine::Engine::METHOD_DOCS.get("start"),
+            Some("Start the engine.")
+        );
+    }
+    #[test]
+    fn rename_map_works() {
+        #[allow(dead_code)]
+        trait Vehicle {
+            fn drive(&self);
+        }
+
+        struct Car;
+
+        #[documented_impl]
+        #[allow(dead_code)]
+        impl Car {
+            /// Honk.
+            fn honk(&self) {}
+        }
+
+        #[documented_impl(rename = "VEHICLE_DOCS")]
+        impl Vehicle for Car {
+            /// Vroom.
+            fn drive(&self) {}
+        }
+
+        assert_eq!(Car::METHOD_DOCS.get("honk"), Some("Honk."));
+        assert_eq!(Car::VEHICLE_DOCS.get("drive"), Some("Vroom."));
+        assert_eq!(Car::VEHICLE_DOCS.get("honk"), None);
+    }
+}
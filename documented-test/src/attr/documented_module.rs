@@ -0,0 +1,201 @@
+use documented::documented_module;
+
+#[documented_module]
+#[allow(dead_code)]
+mod shapes {
+    /// A round shape.
+    pub struct Circle;
+
+    /// Compute the area.
+    pub fn area() -> f64 {
+        0.0
+    }
+
+    /// Kinds of corners.
+    enum Corner {
+        Sharp,
+        Round,
+    }
+
+    /// The number of sides.
+    const SIDES: usize = 0;
+
+    /// Nested shapes.
+    pub mod nested {
+        /// A shape with three corners.
+        pub struct Triangle;
+    }
+
+    pub struct Undocumented;
+
+    impl Circle {}
+}
+
+#[test]
+fn it_works() {
+    assert_eq!(shapes::MODULE_DOCS.get("Circle"), Some("A round shape."));
+    assert_eq!(shapes::MODULE_DOCS.get("area"), Some("Compute the area."));
+    assert_eq!(shapes::MODULE_DOCS.get("Corner"), Some("Kinds of corners."));
+    assert_eq!(
+        shapes::MODULE_DOCS.get("SIDES"),
+        Some("The number of sides.")
+    );
+    assert_eq!(shapes::MODULE_DOCS.get("nested"), Some("Nested shapes."));
+    assert_eq!(
+        shapes::MODULE_DOCS.names().collect::<Vec<_>>(),
+        ["Circle", "area", "Corner", "SIDES", "nested"]
+    );
+}
+
+#[test]
+fn undocumented_items_are_skipped() {
+    assert!(!shapes::MODULE_DOCS.contains("Undocumented"));
+}
+
+#[test]
+fn nested_items_are_not_included_by_default() {
+    assert!(!shapes::MODULE_DOCS.contains("nested::Triangle"));
+}
+
+#[test]
+fn empty_module_works() {
+    #[documented_module]
+    mod empty {}
+
+    assert!(empty::MODULE_DOCS.is_empty());
+}
+
+#[cfg(feature = "customise")]
+mod test_customise {
+    use documented::documented_module;
+
+    #[test]
+    fn skip_private_works() {
+        #[documented_module(skip_private)]
+        #[allow(dead_code)]
+        mod shapes {
+            /// A round shape.
+            pub struct Circle;
+
+            /// A crate-visible shape.
+            pub(crate) struct Square;
+
+            /// A private shape.
+            struct Secret;
+        }
+
+        assert_eq!(
+            shapes::MODULE_DOCS.names().collect::<Vec<_>>(),
+            ["Circle", "Square"]
+        );
+    }
+
+    #[test]
+    fn recursive_works() {
+        #[documented_module(recursive)]
+        #[allow(dead_code)]
+        mod shapes {
+            /// A round shape.
+            pub struct Circle;
+
+            /// Shapes with corners.
+            pub mod polygons {
+                /// A shape with three corners.
+                pub struct Triangle;
+
+                /// Shapes with four corners.
+                pub mod quads {
+                    /// A square.
+                    pub struct Square;
+                }
+            }
+        }
+
+        assert_eq!(
+            shapes::MODULE_DOCS.names().collect::<Vec<_>>(),
+            [
+                "Circle",
+                "polygons",
+                "polygons::Triangle",
+                "polygons::quads",
+                "polygons::quads::Square"
+            ]
+        );
+        assert_eq!(
+            shapes::MODULE_DOCS.get("polygons::quads::Square"),
+            Some("A square.")
+        );
+    }
+
+    #[test]
+    fn recursive_skip_private_works() {
+        #[documented_module(recursive, skip_private)]
+        #[allow(dead_code)]
+        mod shapes {
+            /// Private shapes.
+            mod hidden {
+                /// A public shape in a private module.
+                pub struct Circle;
+            }
+
+            /// Public shapes.
+            pub mod visible {
+                /// A shape with three corners.
+                pub struct Triangle;
+            }
+        }
+
+        assert_eq!(
+            shapes::MODULE_DOCS.names().collect::<Vec<_>>(),
+            ["visible", "visible::Triangle"]
+        );
+    }
+
+    #[test]
+    fn default_works() {
+        #[documented_module(default = "No docs.")]
+        #[allow(dead_code)]
+        mod shapes {
+            /// A round shape.
+            pub struct Circle;
+
+            pub struct Square;
+        }
+
+        assert_eq!(shapes::MODULE_DOCS.get("Circle"), Some("A round shape."));
+        assert_eq!(shapes::MODULE_DOCS.get("Square"), Some("No docs."));
+    }
+
+    #[test]
+    fn vis_works() {
+        mod outer {
+            #[documented::documented_module(vis = pub(super))]
+            #[allow(dead_code)]
+            pub mod shapes {
+                /// A round shape.
+                pub struct Circle;
+            }
+
+            pub fn circle_docs() -> Option<&'static str> {
+                shapes::MODULE_DOCS.get("Circle")
+            }
+        }
+
+        assert_eq!(outer::circle_docs(), Some("A round shape."));
+    }
+
+    #[test]
+    fn trim_false_works() {
+        #[documented_module(trim = false)]
+        #[allow(dead_code)]
+        mod shapes {
+            ///     A round shape.
+            pub struct Circle;
+        }
+
+        assert_eq!(
+            shapes::MODULE_DOCS.get("Circle"),
+            Some("     A round shape.")
+        );
+    }
+}
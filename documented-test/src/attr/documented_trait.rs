@@ -0,0 +1,202 @@
+use documented::{documented_trait, Documented};
+
+#[test]
+fn it_works() {
+    /// Extends the host.
+    #[documented_trait]
+    #[allow(dead_code)]
+    trait Plugin {
+        /// The name of the plugin.
+        fn name(&self) -> &'static str;
+
+        /// Called when the plugin is loaded.
+        fn on_load(&mut self) {}
+    }
+
+    assert_eq!(<dyn Plugin as Documented>::DOCS, "Extends the host.");
+    assert_eq!(PLUGIN_ITEM_DOCS.len(), 2);
+    assert_eq!(
+        PLUGIN_ITEM_DOCS.get("name"),
+        Some("The name of the plugin.")
+    );
+    assert_eq!(
+        PLUGIN_ITEM_DOCS.get("on_load"),
+        Some("Called when the plugin is loaded.")
+    );
+    assert_eq!(PLUGIN_ITEM_DOCS.get("on_unload"), None);
+}
+
+#[test]
+fn generic_trait_works() {
+    /// Converts things.
+    #[documented_trait]
+    #[allow(dead_code)]
+    trait Converter<T>
+    where
+        T: Copy,
+    {
+        /// Convert a thing.
+        fn convert(&self, value: T) -> T;
+    }
+
+    assert_eq!(<dyn Converter<u8> as Documented>::DOCS, "Converts things.");
+    assert_eq!(CONVERTER_ITEM_DOCS.get("convert"), Some("Convert a thing."));
+}
+
+#[test]
+fn not_dyn_compatible_trait_works() {
+    /// Parses things.
+    #[documented_trait]
+    #[allow(dead_code)]
+    trait Parser {
+        /// The parsed thing.
+        type Output;
+        /// How many times to retry.
+        const RETRIES: usize = 3;
+        /// Parse a thing.
+        fn parse<S: AsRef<str>>(&self, input: S) -> Self::Output;
+    }
+
+    /// Makes things.
+    #[documented_trait]
+    #[allow(dead_code)]
+    trait Factory: Sized {
+        /// Make a new thing.
+        fn new() -> Self;
+    }
+
+    assert_eq!(PARSER_ITEM_DOCS.get("Output"), Some("The parsed thing."));
+    assert_eq!(PARSER_ITEM_DOCS.get("parse"), Some("Parse a thing."));
+    assert_eq!(FACTORY_ITEM_DOCS.get("new"), Some("Make a new thing."));
+}
+
+#[test]
+fn supertraits_work() {
+    /// Clones things.
+    #[documented_trait]
+    #[allow(dead_code)]
+    trait Cloner: Clone {
+        /// Clone a thing.
+        fn clone_it(&self) -> u8;
+    }
+
+    /// Makes things.
+    #[documented_trait]
+    #[allow(dead_code)]
+    trait Maker
+    where
+        Self: Default,
+    {
+        /// Make a thing.
+        fn make_it(&self) -> u8;
+    }
+
+    /// Sends things.
+    #[documented_trait]
+    #[allow(dead_code)]
+    trait Sender: Send + Sync + 'static {
+        /// Send a thing.
+        fn send_it(&self);
+    }
+
+    assert_eq!(CLONER_ITEM_DOCS.get("clone_it"), Some("Clone a thing."));
+    assert_eq!(MAKER_ITEM_DOCS.get("make_it"), Some("Make a thing."));
+    assert_eq!(<dyn Sender as Documented>::DOCS, "Sends things.");
+}
+
+#[test]
+fn sized_methods_are_dyn_compatible() {
+    /// Draws things.
+    #[documented_trait]
+    #[allow(dead_code)]
+    trait Canvas {
+        /// Draw a thing.
+        fn draw(&mut self, thing: &str);
+
+        /// Make a new canvas.
+        fn new() -> Self
+        where
+            Self: Sized;
+    }
+
+    assert_eq!(<dyn Canvas as Documented>::DOCS, "Draws things.");
+}
+
+#[cfg(feature = "customise")]
+mod test_customise {
+    use documented::{documented_trait, Documented};
+
+    #[test]
+    fn dyn_impl_false_works() {
+        #[documented_trait(dyn_impl = false)]
+        #[allow(dead_code)]
+        trait Shape: Sized {
+            /// Make a new shape.
+            fn new() -> Self;
+        }
+
+        assert_eq!(SHAPE_ITEM_DOCS.get("new"), Some("Make a new shape."));
+    }
+
+    #[test]
+    fn items_are_documented() {
+        /// Fails on purpose.
+        #[documented_trait(dyn_impl = false)]
+        #[allow(dead_code)]
+        trait Fallible {
+            /// What went wrong.
+            type Error;
+            /// How many times to retry.
+            const RETRIES: usize = 3;
+        }
+
+        assert_eq!(
+            FALLIBLE_ITEM_DOCS.entries(),
+            [
+                ("Error", "What went wrong."),
+                ("RETRIES", "How many times to retry.")
+            ]
+        );
+    }
+
+    #[test]
+    fn options_work() {
+        mod plugins {
+            use documented::documented_trait;
+
+            /// Extends the host.
+            #[documented_trait(
+                vis = pub,
+                rename_all = "kebab-case",
+                default = "Undocumented.",
+                trim = false
+            )]
+            #[allow(dead_code)]
+            trait Plugin {
+                fn on_load(&mut self) {}
+
+                ///   Called when the plugin is unloaded.
+                #[documented_trait(rename = "unload", trim = true)]
+                fn on_unload(&mut self) {}
+            }
+        }
+
+        assert_eq!(
+            plugins::PLUGIN_ITEM_DOCS.get("on-load"),
+            Some("Undocumented.")
+        );
+        assert_eq!(
+            plugins::PLUGIN_ITEM_DOCS.get("unload"),
+            Some("Called when the plugin is unloaded.")
+        );
+    }
+
+    #[test]
+    fn default_works_for_trait() {
+        #[documented_trait(default = "Undocumented.")]
+        #[allow(dead_code)]
+        trait Plugin {}
+
+        assert_eq!(<dyn Plugin as Documented>::DOCS, "Undocumented.");
+    }
+}
@@ -0,0 +1,71 @@
+mod no_traits {
+    //! ```
+    //! #[documented::auto(Documented)]
+    //! mod model {
+    //!     /// A user.
+    //!     pub struct User;
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[documented::auto()]
+    //! mod model {
+    //!     /// A user.
+    //!     pub struct User;
+    //! }
+    //! ```
+}
+
+mod unknown_trait {
+    //! ```compile_fail
+    //! #[documented::auto(Debug)]
+    //! mod model {
+    //!     /// A user.
+    //!     pub struct User;
+    //! }
+    //! ```
+}
+
+mod multiple_flavours {
+    //! ```compile_fail
+    //! #[documented::auto(Documented, DocumentedOpt)]
+    //! mod model {
+    //!     /// A user.
+    //!     pub struct User;
+    //! }
+    //! ```
+}
+
+mod not_inline {
+    //! ```compile_fail
+    //! #[documented::auto(Documented)]
+    //! mod model;
+    //! ```
+}
+
+mod missing_docs {
+    //! ```compile_fail
+    //! #[documented::auto(Documented)]
+    //! mod model {
+    //!     pub struct User;
+    //! }
+    //! ```
+}
+
+mod unknown_option {
+    //! ```
+    //! #[documented::auto(Documented)]
+    //! mod model {
+    //!     #[documented_auto(skip)]
+    //!     pub struct User;
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[documented::auto(Documented)]
+    //! mod model {
+    //!     #[documented_auto(ignore)]
+    //!     pub struct User;
+    //! }
+    //! ```
+}
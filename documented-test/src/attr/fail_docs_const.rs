@@ -5,6 +5,37 @@ mod missing_docs {
     //! ```
 }
 
+mod missing_docs_in_extern_block {
+    //! ```
+    //! #[documented::docs_const]
+    //! extern "C" {
+    //!     /// Compute the absolute value.
+    //!     fn abs(input: i32) -> i32;
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[documented::docs_const]
+    //! extern "C" {
+    //!     /// Compute the absolute value.
+    //!     fn abs(input: i32) -> i32;
+    //!
+    //!     static errno: i32;
+    //! }
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod rename_extern_block {
+    //! ```compile_fail
+    //! #[documented::docs_const(rename = "FFI_DOCS")]
+    //! extern "C" {
+    //!     /// Compute the absolute value.
+    //!     fn abs(input: i32) -> i32;
+    //! }
+    //! ```
+}
+
 #[cfg(feature = "customise")]
 mod non_applicable_options {
     //! ```
@@ -53,6 +84,27 @@ mod non_unique_options {
     //! ```
 }
 
+#[cfg(feature = "customise")]
+mod invalid_trim_mode {
+    //! ```
+    //! /// If you can call them people.
+    //! #[documented::docs_const(trim = "dedent")]
+    //! struct NinetyNinePercentOfPeople;
+    //! ```
+    //!
+    //! ```compile_fail
+    //! /// If you can call them people.
+    //! #[documented::docs_const(trim = "some")]
+    //! struct NinetyNinePercentOfPeople;
+    //! ```
+    //!
+    //! ```compile_fail
+    //! /// If you can call them people.
+    //! #[documented::docs_const(trim = 1)]
+    //! struct NinetyNinePercentOfPeople;
+    //! ```
+}
+
 mod illegal_item {
     //! ```compile_fail
     //! struct Horrible;
@@ -0,0 +1,158 @@
+mod missing_docs {
+    //! ```
+    //! struct Engine;
+    //!
+    //! #[documented::documented_impl]
+    //! impl Engine {
+    //!     /// Start the engine.
+    //!     fn start(&self) {}
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! struct Engine;
+    //!
+    //! #[documented::documented_impl]
+    //! impl Engine {
+    //!     fn start(&self) {}
+    //! }
+    //! ```
+}
+
+mod inherited_private_visibility {
+    //! ```
+    //! mod engine {
+    //!     pub struct Engine;
+    //!
+    //!     #[documented::documented_impl]
+    //!     impl Engine {
+    //!         /// Start the engine.
+    //!         pub fn start(&self) {}
+    //!     }
+    //! }
+    //! assert_eq!(engine::Engine::START_DOCS, "Start the engine.");
+    //! ```
+    //!
+    //! ```compile_fail
+    //! mod engine {
+    //!     pub struct Engine;
+    //!
+    //!     #[documented::documented_impl]
+    //!     impl Engine {
+    //!         /// Start the engine.
+    //!         fn start(&self) {}
+    //!     }
+    //! }
+    //! assert_eq!(engine::Engine::START_DOCS, "Start the engine.");
+    //! ```
+    //!
+    //! ```compile_fail
+    //! mod engine {
+    //!     pub struct Engine;
+    //!
+    //!     #[documented::documented_impl]
+    //!     impl Engine {
+    //!         /// Start the engine.
+    //!         pub fn start(&self) {}
+    //!     }
+    //! }
+    //! assert!(engine::Engine::METHOD_DOCS.get("start").is_some());
+    //! ```
+}
+
+mod duplicate_consts {
+    //! ```
+    //! struct Engine;
+    //!
+    //! #[documented::documented_impl]
+    //! impl Engine {
+    //!     /// Start the engine.
+    //!     fn run(&self) {}
+    //!
+    //!     /// Whether the engine is running.
+    //!     const RUNNING: bool = false;
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! struct Engine;
+    //!
+    //! #[documented::documented_impl]
+    //! impl Engine {
+    //!     /// Start the engine.
+    //!     fn run(&self) {}
+    //!
+    //!     /// Whether the engine should run.
+    //!     const RUN: bool = false;
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! struct Engine;
+    //!
+    //! #[documented::documented_impl]
+    //! impl Engine {
+    //!     /// Start the engine.
+    //!     fn method(&self) {}
+    //! }
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod non_applicable_options {
+    //! ```
+    //! struct Engine;
+    //!
+    //! #[documented::documented_impl()]
+    //! impl Engine {
+    //!     /// Start the engine.
+    //!     #[documented_impl()]
+    //!     fn start(&self) {}
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! struct Engine;
+    //!
+    //! #[documented::documented_impl(rename = "engine docs")]
+    //! impl Engine {
+    //!     /// Start the engine.
+    //!     fn start(&self) {}
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! struct Engine;
+    //!
+    //! #[documented::documented_impl]
+    //! impl Engine {
+    //!     /// Start the engine.
+    //!     #[documented_impl(vis = pub)]
+    //!     fn start(&self) {}
+    //! }
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod non_unique_options {
+    //! ```compile_fail
+    //! struct Engine;
+    //!
+    //! #[documented::documented_impl(trim = true, trim = false)]
+    //! impl Engine {
+    //!     /// Start the engine.
+    //!     fn start(&self) {}
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! struct Engine;
+    //!
+    //! #[documented::documented_impl]
+    //! impl Engine {
+    //!     /// Start the engine.
+    //!     #[documented_impl(rename = "go", rename = "run")]
+    //!     fn start(&self) {}
+    //! }
+    //! ```
+}
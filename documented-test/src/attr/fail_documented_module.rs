@@ -0,0 +1,49 @@
+mod not_inline {
+    //! ```compile_fail
+    //! #[documented::documented_module]
+    //! mod shapes;
+    //! ```
+}
+
+mod duplicate_names {
+    //! ```
+    //! #[documented::documented_module]
+    //! mod shapes {
+    //!     /// A round shape.
+    //!     pub struct Circle;
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[documented::documented_module]
+    //! mod shapes {
+    //!     /// A round shape.
+    //!     pub struct Circle;
+    //!
+    //!     /// A module, also named `Circle`.
+    //!     pub mod circle_mod {}
+    //!
+    //!     /// Make a circle.
+    //!     #[allow(non_snake_case)]
+    //!     pub fn Circle() {}
+    //! }
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod not_applicable_options {
+    //! ```compile_fail
+    //! #[documented::documented_module(rename_all = "kebab-case")]
+    //! mod shapes {}
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[documented::documented_module(rename = "SHAPES")]
+    //! mod shapes {}
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[documented::documented_module(dyn_impl = false)]
+    //! mod shapes {}
+    //! ```
+}
@@ -0,0 +1,128 @@
+mod missing_docs {
+    //! ```
+    //! /// Extends the host.
+    //! #[documented::documented_trait]
+    //! trait Plugin {
+    //!     /// Called when the plugin is loaded.
+    //!     fn on_load(&mut self) {}
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[documented::documented_trait]
+    //! trait Plugin {
+    //!     /// Called when the plugin is loaded.
+    //!     fn on_load(&mut self) {}
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! /// Extends the host.
+    //! #[documented::documented_trait]
+    //! trait Plugin {
+    //!     fn on_load(&mut self) {}
+    //! }
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod missing_docs_without_dyn_impl {
+    //! ```
+    //! #[documented::documented_trait(dyn_impl = false)]
+    //! trait Plugin {
+    //!     /// Called when the plugin is loaded.
+    //!     fn on_load(&mut self) {}
+    //! }
+    //! ```
+}
+
+mod not_dyn_compatible {
+    //! ```compile_fail
+    //! use documented::Documented;
+    //!
+    //! /// A shape.
+    //! #[documented::documented_trait]
+    //! trait Shape: Sized {
+    //!     /// Make a new shape.
+    //!     fn new() -> Self;
+    //! }
+    //!
+    //! assert_eq!(<dyn Shape as Documented>::DOCS, "A shape.");
+    //! ```
+    //!
+    //! ```compile_fail
+    //! use documented::Documented;
+    //!
+    //! /// A shape.
+    //! #[documented::documented_trait]
+    //! trait Shape: Clone {
+    //!     /// The area of the shape.
+    //!     fn area(&self) -> f64;
+    //! }
+    //!
+    //! assert_eq!(<dyn Shape as Documented>::DOCS, "A shape.");
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod forced_dyn_impl {
+    //! ```
+    //! /// A shape.
+    //! #[documented::documented_trait(dyn_impl = true)]
+    //! trait Shape {
+    //!     /// The area of the shape.
+    //!     fn area(&self) -> f64;
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! /// A shape.
+    //! #[documented::documented_trait(dyn_impl = true)]
+    //! trait Shape: Sized {
+    //!     /// Make a new shape.
+    //!     fn new() -> Self;
+    //! }
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod non_applicable_options {
+    //! ```
+    //! /// Extends the host.
+    //! #[documented::documented_trait()]
+    //! trait Plugin {
+    //!     /// Called when the plugin is loaded.
+    //!     #[documented_trait()]
+    //!     fn on_load(&mut self) {}
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! /// Extends the host.
+    //! #[documented::documented_trait(rename = "plugin")]
+    //! trait Plugin {
+    //!     /// Called when the plugin is loaded.
+    //!     fn on_load(&mut self) {}
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! /// Extends the host.
+    //! #[documented::documented_trait]
+    //! trait Plugin {
+    //!     /// Called when the plugin is loaded.
+    //!     #[documented_trait(dyn_impl = false)]
+    //!     fn on_load(&mut self) {}
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! struct Engine;
+    //!
+    //! #[documented::documented_impl(dyn_impl = false)]
+    //! impl Engine {
+    //!     /// Start the engine.
+    //!     fn start(&self) {}
+    //! }
+    //! ```
+}
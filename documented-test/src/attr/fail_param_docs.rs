@@ -0,0 +1,65 @@
+mod nonexistent_param {
+    //! ```
+    //! /// # Arguments
+    //! ///
+    //! /// * `from` - Where from.
+    //! #[documented::param_docs]
+    //! fn make_move(from: u8) {}
+    //! ```
+    //!
+    //! ```compile_fail
+    //! /// # Arguments
+    //! ///
+    //! /// * `from` - Where from.
+    //! /// * `to` - Where to.
+    //! #[documented::param_docs]
+    //! fn make_move(from: u8) {}
+    //! ```
+}
+
+mod non_ident_pattern {
+    //! ```
+    //! #[documented::param_docs]
+    //! fn make_move(#[param_doc = "Where from and to."] squares: (u8, u8)) {}
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[documented::param_docs]
+    //! fn make_move(#[param_doc = "Where from and to."] (from, to): (u8, u8)) {}
+    //! ```
+}
+
+mod illegal_item {
+    //! ```compile_fail
+    //! /// # Arguments
+    //! ///
+    //! /// * `from` - Where from.
+    //! #[documented::param_docs]
+    //! struct Move {
+    //!     from: u8,
+    //! }
+    //! ```
+}
+
+mod inherited_private_visibility {
+    //! ```compile_fail
+    //! mod game {
+    //!     #[documented::param_docs]
+    //!     fn make_move(#[param_doc = "Where from."] from: u8) {}
+    //! }
+    //! assert!(game::MAKE_MOVE_PARAM_DOCS.get("from").is_some());
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod non_applicable_options {
+    //! ```
+    //! #[documented::param_docs()]
+    //! fn make_move(#[param_doc = "Where from."] from: u8) {}
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[documented::param_docs(rename_all = "kebab-case")]
+    //! fn make_move(#[param_doc = "Where from."] from: u8) {}
+    //! ```
+}
@@ -0,0 +1,124 @@
+use documented::param_docs;
+
+#[test]
+fn param_doc_attrs_work() {
+    #[param_docs]
+    #[allow(dead_code, unused_variables)]
+    fn test_fn(
+        #[param_doc = "The first one."] first: u8,
+        #[param_doc = "The second one,"]
+        #[param_doc = "  which is special."]
+        second: u8,
+        undocumented: u8,
+    ) {
+    }
+
+    assert_eq!(
+        TEST_FN_PARAM_DOCS.entries(),
+        [
+            ("first", "The first one."),
+            ("second", "The second one,\nwhich is special.")
+        ]
+    );
+    assert_eq!(TEST_FN_PARAM_DOCS.get("undocumented"), None);
+}
+
+#[test]
+fn arguments_section_works() {
+    /// Does things.
+    ///
+    /// # Arguments
+    ///
+    /// * `first` - The first one.
+    /// - `second`: The second one,
+    ///   which is special.
+    ///
+    /// # Panics
+    ///
+    /// * `never` - Not an argument.
+    #[param_docs]
+    #[allow(dead_code, unused_variables)]
+    fn test_fn(first: u8, second: u8) {}
+
+    assert_eq!(
+        TEST_FN_PARAM_DOCS.entries(),
+        [
+            ("first", "The first one."),
+            ("second", "The second one,\nwhich is special.")
+        ]
+    );
+}
+
+#[test]
+fn param_doc_attrs_take_priority() {
+    /// # Arguments
+    ///
+    /// * `first` - From the docs.
+    /// * `second` - From the docs.
+    #[param_docs]
+    #[allow(dead_code, unused_variables)]
+    fn test_fn(first: u8, #[param_doc = "From the attribute."] second: u8) {}
+
+    assert_eq!(
+        TEST_FN_PARAM_DOCS.entries(),
+        [
+            ("first", "From the docs."),
+            ("second", "From the attribute.")
+        ]
+    );
+}
+
+#[test]
+fn methods_and_patterns_are_skipped() {
+    struct Board;
+
+    impl Board {
+        /// # Arguments
+        ///
+        /// * `square` - Where.
+        #[param_docs]
+        #[allow(dead_code, unused_variables)]
+        fn place(&self, square: u8, (_, _): (u8, u8)) {}
+    }
+
+    // in an impl block, the constant is an associated constant
+    assert_eq!(Board::PLACE_PARAM_DOCS.entries(), [("square", "Where.")]);
+}
+
+#[cfg(feature = "customise")]
+mod test_customise {
+    use documented::param_docs;
+
+    #[test]
+    fn options_work() {
+        mod engine {
+            use documented::param_docs;
+
+            #[param_docs(vis = pub, rename = "ARGS", default = "Obvious.", trim = false)]
+            #[allow(dead_code, unused_variables)]
+            fn test_fn(#[param_doc = "  Spaced out."] first: u8, second: u8) {}
+        }
+
+        assert_eq!(
+            engine::ARGS.entries(),
+            [("first", "  Spaced out."), ("second", "Obvious.")]
+        );
+    }
+
+    #[test]
+    fn dedent_works() {
+        #[param_docs(trim = "dedent")]
+        #[allow(dead_code, unused_variables)]
+        fn test_fn(
+            #[param_doc = "  Spaced out,"]
+            #[param_doc = "    and more."]
+            first: u8,
+        ) {
+        }
+
+        assert_eq!(
+            TEST_FN_PARAM_DOCS.get("first"),
+            Some("Spaced out,\n  and more.")
+        );
+    }
+}
@@ -47,6 +47,61 @@ mod test_use {
         assert_eq!(FactOfLife::DOCS, docs)
     }
 
+    #[test]
+    fn built_in_macros_are_trimmed() {
+        #[doc = concat!("  something something\n", "    death and taxes  ")]
+        #[doc = include_str!("external_docs.md")]
+        #[doc = std::env!("CARGO_PKG_NAME")]
+        #[derive(Documented)]
+        struct FactOfLife;
+
+        let docs = "something something\ndeath and taxes\n\
+            Kept in an external file.\nIndented for no good reason.\n\n\
+            documented-test";
+        assert_eq!(FactOfLife::DOCS, docs)
+    }
+
+    #[test]
+    fn nested_built_in_macros_work() {
+        #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/derive/external_docs.md"))]
+        #[derive(Documented)]
+        struct FactOfLife;
+
+        let docs = "Kept in an external file.\nIndented for no good reason.\n";
+        assert_eq!(FactOfLife::DOCS, docs)
+    }
+
+    #[test]
+    fn cfg_attr_works() {
+        #[cfg_attr(any(), doc = "Never compiled.")]
+        /// Famous saying:
+        #[cfg_attr(all(), doc = "something something")]
+        #[cfg_attr(any(), doc = "life and taxes")]
+        #[cfg_attr(all(), cfg_attr(all(), doc = "death and taxes"))]
+        #[derive(Documented)]
+        struct FactOfLife;
+
+        let docs = "Famous saying:\nsomething something\ndeath and taxes";
+        assert_eq!(FactOfLife::DOCS, docs)
+    }
+
+    #[test]
+    fn unknown_macros_are_passed_through() {
+        macro_rules! fact {
+            () => {
+                "  death and taxes  "
+            };
+        }
+
+        /// Famous saying:
+        #[doc = fact!()]
+        #[derive(Documented)]
+        struct FactOfLife;
+
+        let docs = "Famous saying:\n  death and taxes  ";
+        assert_eq!(FactOfLife::DOCS, docs)
+    }
+
     #[test]
     fn generic_type_works() {
         /// Wow
@@ -96,6 +151,92 @@ mod test_use {
     }
 }
 
+mod test_parts {
+    use documented::{Documented, DocumentedParts, Section};
+
+    #[test]
+    fn summary_and_body_work() {
+        /// The summary
+        /// spans two lines.
+        ///
+        ///
+        /// The body.
+        ///
+        /// More body.
+        #[derive(Documented)]
+        struct Nice;
+
+        assert_eq!(Nice::SUMMARY, "The summary\nspans two lines.");
+        assert_eq!(Nice::BODY, "The body.\n\nMore body.");
+    }
+
+    #[test]
+    fn summary_only_works() {
+        /// Just this.
+        #[derive(Documented)]
+        struct Nice;
+
+        assert_eq!(Nice::SUMMARY, "Just this.");
+        assert_eq!(Nice::BODY, "");
+        assert_eq!(Nice::sections().count(), 0);
+    }
+
+    #[test]
+    fn macro_form_works() {
+        #[doc = concat!("Summary.\n", "\n", "# Errors\n", "Never.")]
+        #[derive(Documented)]
+        struct Nice;
+
+        assert_eq!(Nice::SUMMARY, "Summary.");
+        assert_eq!(Nice::BODY, "# Errors\nNever.");
+        assert_eq!(Nice::get_section("Errors"), Some("Never."));
+    }
+
+    #[test]
+    fn sections_work() {
+        /// Summary.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// # not a heading
+        /// ```
+        ///
+        /// ## Nested
+        ///
+        /// Deep.
+        ///
+        /// # Safety
+        /// Always.
+        #[derive(Documented)]
+        struct Nice;
+
+        let sections = Nice::sections().collect::<Vec<_>>();
+        assert_eq!(
+            sections,
+            [
+                Section {
+                    heading: "Examples",
+                    level: 1,
+                    content: "```\n# not a heading\n```\n\n## Nested\n\nDeep.",
+                },
+                Section {
+                    heading: "Nested",
+                    level: 2,
+                    content: "Deep."
+                },
+                Section {
+                    heading: "Safety",
+                    level: 1,
+                    content: "Always."
+                },
+            ]
+        );
+        assert_eq!(Nice::get_section("Safety"), Some("Always."));
+        assert_eq!(Nice::get_section("not a heading"), None);
+    }
+}
+
 mod test_qualified {
     #[test]
     fn it_works() {
@@ -191,4 +332,121 @@ doge
         ";
         assert_eq!(Doge::DOCS, doc_str);
     }
+
+    #[test]
+    fn trim_all_works() {
+        ///   Wow
+        ///     much
+        #[derive(Documented)]
+        #[documented(trim = "all")]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "Wow\nmuch");
+    }
+
+    #[test]
+    fn trim_dedent_works() {
+        ///
+        /// A list:
+        /// - item
+        ///   - nested item
+        ///
+        /// ```
+        /// fn foo() {
+        ///     bar();
+        /// }
+        /// ```
+        ///
+        #[derive(Documented)]
+        #[documented(trim = "dedent")]
+        struct Doge;
+
+        let doc_str = "A list:
+- item
+  - nested item
+
+```
+fn foo() {
+    bar();
+}
+```";
+        assert_eq!(Doge::DOCS, doc_str);
+    }
+
+    #[test]
+    fn trim_dedent_ignores_non_ascii_whitespace() {
+        /// Lines:
+        ///　text
+        #[derive(Documented)]
+        #[documented(trim = "dedent")]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, " Lines:\n\u{3000}text");
+    }
+
+    #[test]
+    fn trim_dedent_works_with_block_comments() {
+        /**
+         * Wow
+         *   much
+         *
+         * doge
+         */
+        #[derive(Documented)]
+        #[documented(trim = "dedent")]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "Wow\n  much\n\ndoge");
+    }
+
+    #[test]
+    fn trim_dedent_works_with_mixed_forms() {
+        /// Wow
+        ///   much
+        #[doc = "doge"]
+        #[doc = concat!("  ", "such")]
+        #[derive(Documented)]
+        #[documented(trim = "dedent")]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "Wow\n  much\ndoge\n  such");
+    }
+
+    #[test]
+    fn transparent_works() {
+        /// A user's unique identifier.
+        #[derive(Documented)]
+        #[allow(dead_code)]
+        struct Id(u64);
+
+        #[derive(Documented)]
+        #[documented(transparent)]
+        #[allow(dead_code)]
+        struct UserId(Id);
+
+        #[derive(Documented)]
+        #[documented(transparent)]
+        #[allow(dead_code)]
+        struct Named {
+            inner: UserId,
+        }
+
+        assert_eq!(UserId::DOCS, "A user's unique identifier.");
+        assert_eq!(Named::DOCS, "A user's unique identifier.");
+    }
+
+    #[test]
+    fn transparent_works_with_generics() {
+        /// A user's unique identifier.
+        #[derive(Documented)]
+        #[allow(dead_code)]
+        struct Id(u64);
+
+        #[derive(Documented)]
+        #[documented(transparent)]
+        #[allow(dead_code)]
+        struct Wrapper<T>(T);
+
+        assert_eq!(Wrapper::<Id>::DOCS, "A user's unique identifier.");
+    }
 }
@@ -1,4 +1,4 @@
-use documented::{DocumentedFields, Error};
+use documented::{field_docs_const, DocumentedFields, Error};
 
 #[test]
 fn it_works() {
@@ -20,6 +20,23 @@ fn it_works() {
     );
 }
 
+#[test]
+fn manual_impl_works() {
+    struct Foo;
+    impl DocumentedFields for Foo {
+        const FIELD_DOCS: &'static [&'static str] = &["1"];
+        const FIELD_NAMES: &'static [&'static str] = &["first"];
+
+        fn __documented_get_index<T: AsRef<str>>(field_name: T) -> Option<usize> {
+            (field_name.as_ref() == "first").then_some(0)
+        }
+    }
+
+    assert!(Foo::FIELDS.is_empty());
+    assert_eq!(Foo::get_field_docs("first"), Ok("1"));
+    assert_eq!(field_docs_const::<Foo>("first"), None);
+}
+
 #[test]
 fn enum_works() {
     #[derive(DocumentedFields)]
@@ -40,6 +57,150 @@ fn enum_works() {
     );
 }
 
+#[test]
+fn enum_variant_fields_work() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    enum Command {
+        /// Deploy the thing.
+        Deploy {
+            /// Target environment.
+            env: String,
+            force: bool,
+        },
+        /// Roll back.
+        Rollback(
+            /// How many releases.
+            usize,
+        ),
+        /// Do nothing.
+        Idle,
+    }
+
+    assert_eq!(Command::FIELD_NAMES, &["Deploy", "Rollback", "Idle"]);
+    assert_eq!(Command::get_field_docs("Deploy"), Ok("Deploy the thing."));
+    assert_eq!(
+        Command::get_field_docs("Deploy.env"),
+        Ok("Target environment.")
+    );
+    assert_eq!(
+        Command::get_field_docs("Deploy.force"),
+        Err(Error::NoDocComments("Deploy.force".into()))
+    );
+    assert_eq!(
+        Command::get_field_docs("Rollback.0"),
+        Ok("How many releases.")
+    );
+    assert_eq!(
+        Command::get_field_docs("Idle.env"),
+        Err(Error::NoSuchField("Idle.env".into()))
+    );
+    assert!(Command::get_field_docs("Deploy.froce")
+        .unwrap_err()
+        .to_string()
+        .ends_with(r#"Command"; did you mean "Deploy.force"?"#));
+}
+
+#[test]
+fn no_such_field_has_details() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Listener {
+        /// The port.
+        port: u16,
+        /// The protocol.
+        protocol: String,
+        /// The host.
+        host: String,
+        /// Flattened.
+        #[cfg(feature = "customise")]
+        #[documented_fields(flatten)]
+        inner: Inner,
+    }
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Inner {
+        /// Some value.
+        value: u8,
+    }
+
+    let Err(Error::NoSuchField(name)) = Listener::get_field_docs("prot") else {
+        panic!("expecting a missing field");
+    };
+    assert_eq!(name, "prot");
+    assert!(name.type_name().unwrap().ends_with("Listener"));
+    assert_eq!(name.suggestions().iter().collect::<Vec<_>>(), ["port"]);
+
+    let err = Listener::get_field_docs("hots").unwrap_err();
+    assert!(err
+        .to_string()
+        .ends_with(r#"Listener"; did you mean "host"?"#));
+    let err = Listener::get_field_docs("nothing-like-it").unwrap_err();
+    assert!(err.to_string().ends_with(r#"Listener""#));
+
+    #[cfg(feature = "customise")]
+    {
+        // the outermost type is reported, along with the full path
+        let err = Listener::get_field_docs("inner.valeu").unwrap_err();
+        let Error::NoSuchField(name) = &err else {
+            panic!("expecting a missing field");
+        };
+        assert_eq!(*name, "inner.valeu");
+        assert!(name.type_name().unwrap().ends_with("Listener"));
+        assert_eq!(name.parent_path(), "inner.");
+        assert_eq!(name.suggestions().iter().collect::<Vec<_>>(), ["value"]);
+        assert!(err
+            .to_string()
+            .ends_with(r#"Listener"; did you mean "inner.value"?"#));
+
+        let err = Listener::get_field_docs("iner.value").unwrap_err();
+        assert!(err.to_string().ends_with(r#"did you mean "inner"?"#));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn long_names_are_kept_in_full() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Foo {
+        /// 1
+        bar: i32,
+    }
+
+    let long = "a_really_long_field_name_that_would_not_fit_in_an_inline_buffer";
+    let err = Foo::get_field_docs(long).unwrap_err();
+    assert_eq!(err, Error::NoSuchField(long.into()));
+    assert!(err.to_string().contains(&format!(r#""{long}""#)));
+}
+
+#[test]
+fn suggestions_are_ranked() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Foo {
+        /// 1
+        colour: i32,
+        /// 2
+        color: i32,
+        /// 3
+        colors: i32,
+        /// 4
+        cooler: i32,
+    }
+
+    let err = Foo::get_field_docs("colr").unwrap_err();
+    let Error::NoSuchField(name) = &err else {
+        panic!("expecting a missing field");
+    };
+    assert_eq!(name.suggestions().iter().collect::<Vec<_>>(), ["color"]);
+
+    let err = Foo::get_field_docs("colours").unwrap_err();
+    assert!(err
+        .to_string()
+        .ends_with(r#"did you mean "colour", "colors" or "color"?"#));
+}
+
 #[test]
 fn union_works() {
     #[derive(DocumentedFields)]
@@ -75,6 +236,89 @@ fn unnamed_fields() {
     assert_eq!(Foo::FIELD_DOCS[2], "2");
 }
 
+#[test]
+fn fields_are_aligned() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Foo(
+        /// 0
+        i32,
+        /// 1
+        u32,
+    );
+
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Bar {
+        /// first
+        first: i32,
+        /// hidden
+        #[doc(hidden)]
+        hidden: i32,
+        /// second
+        second: u32,
+    }
+
+    assert!(Foo::FIELD_NAMES.is_empty());
+    assert_eq!(Foo::FIELDS, [(None, "0"), (None, "1")]);
+    assert_eq!(
+        Bar::FIELDS,
+        [(Some("first"), "first"), (Some("second"), "second")]
+    );
+}
+
+#[test]
+fn doc_aliases_work() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Foo(
+        /// 0
+        #[doc(alias = "zeroth")]
+        i32,
+        /// 1
+        #[doc(alias("first", "one"))]
+        u32,
+    );
+
+    assert_eq!(Foo::FIELD_NAMES, &[] as &[&str]);
+    assert_eq!(Foo::FIELD_ALIASES, [&["zeroth"][..], &["first", "one"]]);
+    assert_eq!(Foo::get_field_docs("zeroth"), Ok("0"));
+    assert_eq!(Foo::get_field_docs("one"), Ok("1"));
+    assert_eq!(field_docs_const::<Foo>("first"), Some("1"));
+}
+
+#[test]
+fn doc_hidden_fields_are_skipped() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    enum Command {
+        /// Deploy the thing.
+        Deploy {
+            /// Target environment.
+            env: String,
+            /// Internal.
+            #[doc(hidden)]
+            token: String,
+        },
+        /// Internal.
+        #[doc(hidden)]
+        Debug,
+        /// Do nothing.
+        Idle,
+    }
+
+    assert_eq!(Command::FIELD_NAMES, &["Deploy", "Idle"]);
+    assert_eq!(Command::FIELD_DOCS, ["Deploy the thing.", "Do nothing."]);
+    assert_eq!(
+        Command::get_field_docs("Debug"),
+        Err(Error::NoSuchField("Debug".into()))
+    );
+    assert_eq!(
+        Command::get_field_docs("Deploy.token"),
+        Err(Error::NoSuchField("Deploy.token".into()))
+    );
+}
+
 #[test]
 fn generic_type_works() {
     #[derive(DocumentedFields)]
@@ -124,9 +368,53 @@ fn lifetimed_type_works() {
     assert_eq!(Foo::get_field_docs("foo"), Ok("foo"));
 }
 
+#[test]
+fn const_lookup_works() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Foo {
+        /// 1
+        first: i32,
+        /// 2
+        second: i32,
+    }
+
+    const FIRST: Option<&str> = field_docs_const::<Foo>("first");
+    const THIRD: Option<&str> = field_docs_const::<Foo>("third");
+    static TABLE: [Option<&str>; 2] = [
+        field_docs_const::<Foo>("second"),
+        field_docs_const::<Foo>("secon"),
+    ];
+
+    assert_eq!(FIRST, Some("1"));
+    assert_eq!(THIRD, None);
+    assert_eq!(TABLE, [Some("2"), None]);
+}
+
+#[test]
+fn const_lookup_works_for_enums_and_generics() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    enum Bar<T> {
+        /// 1
+        First(T),
+        /// 2
+        Second {
+            /// 2.1
+            inner: T,
+        },
+    }
+
+    const SECOND: Option<&str> = field_docs_const::<Bar<u8>>("Second");
+    const NESTED: Option<&str> = field_docs_const::<Bar<u8>>("Second.inner");
+
+    assert_eq!(SECOND, Some("2"));
+    assert_eq!(NESTED, None);
+}
+
 #[cfg(feature = "customise")]
 mod test_customise {
-    use documented::DocumentedFields;
+    use documented::{field_docs_const, DocumentedFields, Error};
 
     #[test]
     fn empty_customise_works() {
@@ -282,4 +570,485 @@ mod test_customise {
         assert_eq!(OkYouWin::FIELD_DOCS, ["Leave me alone.", "Just kidding."]);
         assert_eq!(OkYouWin::get_field_docs("ahhh"), Ok("Leave me alone."));
     }
+
+    #[test]
+    fn positional_names_work() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(positional_names)]
+        #[allow(dead_code)]
+        struct OkYouWin(
+            /// Leave me alone.
+            #[documented_fields(rename = "ahhh")]
+            (),
+            /// Hidden.
+            #[documented_fields(skip)]
+            (),
+            /// Just kidding.
+            usize,
+        );
+
+        #[derive(DocumentedFields)]
+        #[documented_fields(positional_names)]
+        #[allow(dead_code)]
+        struct Named {
+            /// Unaffected.
+            field: u8,
+        }
+
+        assert_eq!(OkYouWin::FIELD_NAMES, &["ahhh", "2"]);
+        assert_eq!(
+            OkYouWin::FIELDS,
+            [
+                (Some("ahhh"), "Leave me alone."),
+                (Some("2"), "Just kidding.")
+            ]
+        );
+        assert_eq!(OkYouWin::get_field_docs("2"), Ok("Just kidding."));
+        assert_eq!(
+            OkYouWin::get_field_docs("0"),
+            Err(Error::NoSuchField("0".into()))
+        );
+        assert_eq!(field_docs_const::<OkYouWin>("2"), Some("Just kidding."));
+        assert_eq!(Named::FIELD_NAMES, &["field"]);
+    }
+
+    #[test]
+    fn const_lookup_uses_renamed_names() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(rename_all = "kebab-case")]
+        #[allow(dead_code)]
+        struct Foo {
+            /// 1
+            first_field: i32,
+            /// 2
+            #[documented_fields(rename = "other")]
+            second_field: i32,
+        }
+
+        const FIRST: Option<&str> = field_docs_const::<Foo>("first-field");
+        const SECOND: Option<&str> = field_docs_const::<Foo>("other");
+
+        assert_eq!(FIRST, Some("1"));
+        assert_eq!(SECOND, Some("2"));
+        assert_eq!(field_docs_const::<Foo>("first_field"), None);
+    }
+
+    #[test]
+    fn flatten_works() {
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Tls {
+            /// Where the cert is.
+            cert_path: String,
+        }
+
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Server {
+            /// TLS settings.
+            #[documented_fields(flatten)]
+            tls: Tls,
+            /// The port.
+            port: u16,
+        }
+
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Server settings.
+            #[documented_fields(flatten = true)]
+            server: Server,
+        }
+
+        assert_eq!(Config::FIELD_NAMES, &["server"]);
+        assert_eq!(Config::FIELD_DOCS, ["Server settings."]);
+        assert_eq!(Config::get_field_docs("server"), Ok("Server settings."));
+        assert_eq!(Config::get_field_docs("server.port"), Ok("The port."));
+        assert_eq!(Config::get_field_docs("server.tls"), Ok("TLS settings."));
+        assert_eq!(
+            Config::get_field_docs("server.tls.cert_path"),
+            Ok("Where the cert is.")
+        );
+        assert_eq!(
+            Config::get_field_docs("server.tls.key_path"),
+            Err(Error::NoSuchField("server.tls.key_path".into()))
+        );
+        assert_eq!(
+            Config::get_field_docs("server.port.number"),
+            Err(Error::NoSuchField("server.port.number".into()))
+        );
+    }
+
+    #[test]
+    fn flatten_works_with_generics() {
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Tls {
+            /// Where the cert is.
+            cert_path: String,
+        }
+
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Server<T> {
+            /// The security settings.
+            #[documented_fields(flatten)]
+            security: T,
+        }
+
+        assert_eq!(
+            Server::<Tls>::get_field_docs("security.cert_path"),
+            Ok("Where the cert is.")
+        );
+    }
+
+    #[test]
+    fn lookup_modes_work() {
+        macro_rules! test_lookup {
+            ($mode:literal) => {{
+                #[derive(DocumentedFields)]
+                #[documented_fields(lookup = $mode, rename_all = "kebab-case")]
+                #[allow(dead_code)]
+                struct Foo {
+                    /// 1
+                    first_field: i32,
+                    /// 2
+                    second_field: i32,
+                    /// 3
+                    #[documented_fields(rename = "a")]
+                    third_field: i32,
+                }
+
+                assert_eq!(Foo::get_field_docs("first-field"), Ok("1"));
+                assert_eq!(Foo::get_field_docs("second-field"), Ok("2"));
+                assert_eq!(Foo::get_field_docs("a"), Ok("3"));
+                assert_eq!(
+                    Foo::get_field_docs("third-field"),
+                    Err(Error::NoSuchField("third-field".into()))
+                );
+            }};
+        }
+
+        test_lookup!("auto");
+        test_lookup!("match");
+        test_lookup!("binary_search");
+        #[cfg(feature = "phf")]
+        test_lookup!("phf");
+    }
+
+    #[test]
+    fn aliases_work() {
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        enum Command {
+            /// Deploy the thing.
+            #[documented_fields(alias = "ship", alias = "release")]
+            Deploy { env: String },
+            /// Do nothing.
+            #[documented_fields(rename = "noop")]
+            #[doc(alias = "idle")]
+            Idle,
+        }
+
+        assert_eq!(Command::FIELD_NAMES, &["Deploy", "noop"]);
+        assert_eq!(
+            Command::FIELD_ALIASES,
+            [&["ship", "release"][..], &["idle"]]
+        );
+        assert_eq!(Command::get_field_docs("release"), Ok("Deploy the thing."));
+        assert_eq!(Command::get_field_docs("idle"), Ok("Do nothing."));
+        assert_eq!(
+            Command::get_field_docs("ship.env"),
+            Err(Error::NoDocComments("ship.env".into()))
+        );
+        assert_eq!(
+            field_docs_const::<Command>("ship"),
+            Some("Deploy the thing.")
+        );
+        assert_eq!(
+            Command::get_field_docs("Idle"),
+            Err(Error::NoSuchField("Idle".into()))
+        );
+    }
+
+    #[test]
+    fn case_insensitive_works() {
+        macro_rules! test_lookup {
+            ($mode:literal) => {{
+                #[derive(DocumentedFields)]
+                #[documented_fields(lookup = $mode, case_insensitive)]
+                #[allow(dead_code)]
+                struct Listener {
+                    /// The port.
+                    #[documented_fields(alias = "Listen_Port")]
+                    port: u16,
+                    /// The host.
+                    #[documented_fields(rename = "hostName")]
+                    host: String,
+                }
+
+                assert_eq!(Listener::FIELD_NAMES, &["port", "hostName"]);
+                assert_eq!(Listener::FIELD_ALIASES, [&["Listen_Port"][..], &[]]);
+                assert_eq!(Listener::get_field_docs("Port"), Ok("The port."));
+                assert_eq!(Listener::get_field_docs("PORT"), Ok("The port."));
+                assert_eq!(Listener::get_field_docs("listen_port"), Ok("The port."));
+                assert_eq!(Listener::get_field_docs("HOSTNAME"), Ok("The host."));
+                assert_eq!(field_docs_const::<Listener>("hostname"), Some("The host."));
+                assert_eq!(
+                    Listener::get_field_docs("a-name-too-long-to-match-anything"),
+                    Err(Error::NoSuchField(
+                        "a-name-too-long-to-match-anything".into()
+                    ))
+                );
+            }};
+        }
+
+        test_lookup!("auto");
+        test_lookup!("match");
+        test_lookup!("binary_search");
+        #[cfg(feature = "phf")]
+        test_lookup!("phf");
+    }
+
+    #[test]
+    fn case_insensitive_applies_to_flattened_paths() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(case_insensitive)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Server settings.
+            #[documented_fields(flatten)]
+            server: Server,
+        }
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Server {
+            /// The port.
+            port: u16,
+        }
+
+        assert_eq!(Config::get_field_docs("SERVER.port"), Ok("The port."));
+        assert_eq!(
+            Config::get_field_docs("server.PORT"),
+            Err(Error::NoSuchField("server.PORT".into()))
+        );
+    }
+
+    #[test]
+    fn skip_works() {
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Config {
+            /// The port.
+            port: u16,
+            #[documented_fields(skip)]
+            cache: Vec<u8>,
+            /// Shown anyway.
+            #[doc(hidden)]
+            #[documented_fields(skip = false)]
+            secret: String,
+            /// The host.
+            #[documented_fields(rename = "hostname")]
+            host: String,
+        }
+
+        assert_eq!(Config::FIELD_NAMES, &["port", "secret", "hostname"]);
+        assert_eq!(
+            Config::FIELD_DOCS,
+            ["The port.", "Shown anyway.", "The host."]
+        );
+        assert_eq!(Config::FIELD_ALIASES.len(), 3);
+        assert_eq!(Config::get_field_docs("hostname"), Ok("The host."));
+        assert_eq!(field_docs_const::<Config>("cache"), None);
+        assert_eq!(documented::docs_of!(Config::host), "The host.");
+        assert_eq!(
+            Config::get_field_docs("cache"),
+            Err(Error::NoSuchField("cache".into()))
+        );
+    }
+
+    #[test]
+    fn include_pub_works() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(include = "pub")]
+        #[allow(dead_code)]
+        struct Config {
+            /// The port.
+            pub port: u16,
+            /// Crate only.
+            pub(crate) internal: u16,
+            /// Private.
+            private: u16,
+            /// Private, but listed.
+            #[documented_fields(skip = false)]
+            listed: u16,
+        }
+
+        #[derive(DocumentedFields)]
+        #[documented_fields(include = "pub")]
+        #[allow(dead_code)]
+        enum Mode {
+            /// Variants are always public.
+            Fast,
+        }
+
+        assert_eq!(Config::FIELD_NAMES, &["port", "listed"]);
+        assert_eq!(Config::FIELD_DOCS, ["The port.", "Private, but listed."]);
+        assert_eq!(Mode::FIELD_NAMES, &["Fast"]);
+    }
+
+    #[test]
+    fn default_from_type_works() {
+        use documented::Documented;
+
+        /// Where the data lives.
+        #[derive(Documented)]
+        #[allow(dead_code)]
+        struct DatabaseConfig {
+            url: String,
+        }
+
+        /// How to log.
+        #[derive(Documented)]
+        #[allow(dead_code)]
+        struct LogConfig {
+            level: u8,
+        }
+
+        #[derive(DocumentedFields)]
+        #[documented_fields(default_from_type)]
+        #[allow(dead_code)]
+        struct AppConfig {
+            database: DatabaseConfig,
+            /// The logger, documented here.
+            logger: LogConfig,
+            #[documented_fields(default = "Nothing to see.")]
+            scratch: LogConfig,
+        }
+
+        #[derive(DocumentedFields)]
+        #[documented_fields(default = "Unknown.")]
+        #[allow(dead_code)]
+        struct Overridden {
+            #[documented_fields(default_from_type)]
+            database: DatabaseConfig,
+            level: u8,
+        }
+
+        assert_eq!(
+            AppConfig::FIELD_DOCS,
+            [
+                "Where the data lives.",
+                "The logger, documented here.",
+                "Nothing to see."
+            ]
+        );
+        assert_eq!(
+            Overridden::FIELD_DOCS,
+            ["Where the data lives.", "Unknown."]
+        );
+    }
+
+    #[test]
+    fn default_from_type_works_with_generics() {
+        use documented::Documented;
+
+        /// Where the data lives.
+        #[derive(Documented)]
+        #[allow(dead_code)]
+        struct DatabaseConfig {
+            url: String,
+        }
+
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct AppConfig<T> {
+            #[documented_fields(default_from_type)]
+            backend: T,
+            /// How many retries.
+            retries: u8,
+        }
+
+        assert_eq!(
+            AppConfig::<DatabaseConfig>::FIELD_DOCS,
+            ["Where the data lives.", "How many retries."]
+        );
+    }
+
+    #[test]
+    fn lookup_modes_work_with_no_fields() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(lookup = "binary_search")]
+        struct Foo;
+
+        #[derive(DocumentedFields)]
+        #[documented_fields(lookup = "match")]
+        struct Bar {}
+
+        assert_eq!(
+            Foo::get_field_docs("first"),
+            Err(Error::NoSuchField("first".into()))
+        );
+        assert_eq!(
+            Bar::get_field_docs("first"),
+            Err(Error::NoSuchField("first".into()))
+        );
+    }
+
+    #[test]
+    fn transparent_works() {
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Settings {
+            /// Whether to use dark mode.
+            #[doc(alias = "theme")]
+            dark_mode: bool,
+            /// How loud.
+            volume: u8,
+        }
+
+        #[derive(DocumentedFields)]
+        #[documented_fields(transparent)]
+        #[allow(dead_code)]
+        struct UserSettings(Settings);
+
+        #[derive(DocumentedFields)]
+        #[documented_fields(transparent)]
+        #[allow(dead_code)]
+        struct Wrapper<T> {
+            inner: T,
+        }
+
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Profile {
+            /// The user's settings.
+            #[documented_fields(flatten)]
+            settings: UserSettings,
+        }
+
+        assert_eq!(UserSettings::FIELD_NAMES, &["dark_mode", "volume"]);
+        assert_eq!(
+            UserSettings::FIELD_DOCS,
+            ["Whether to use dark mode.", "How loud."]
+        );
+        assert_eq!(UserSettings::FIELD_ALIASES, Settings::FIELD_ALIASES);
+        assert_eq!(
+            UserSettings::get_field_docs("theme"),
+            Ok("Whether to use dark mode.")
+        );
+        assert_eq!(
+            field_docs_const::<UserSettings>("theme"),
+            Some("Whether to use dark mode.")
+        );
+        assert_eq!(
+            Wrapper::<Settings>::get_field_docs("volume"),
+            Ok("How loud.")
+        );
+        assert_eq!(Profile::get_field_docs("settings.volume"), Ok("How loud."));
+        assert_eq!(
+            UserSettings::get_field_docs("inner"),
+            Err(Error::NoSuchField("inner".into()))
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use documented::{DocumentedFieldsOpt, Error};
+use documented::{field_docs_opt_const, DocumentedFieldsOpt, Error};
 
 #[test]
 fn it_works() {
@@ -26,6 +26,20 @@ fn it_works() {
     );
 }
 
+#[test]
+fn fields_are_aligned() {
+    #[derive(DocumentedFieldsOpt)]
+    #[allow(dead_code)]
+    struct Foo(
+        /// 0
+        i32,
+        u32,
+    );
+
+    assert!(Foo::FIELD_NAMES.is_empty());
+    assert_eq!(Foo::FIELDS, [(None, Some("0")), (None, None)]);
+}
+
 #[test]
 fn enum_works() {
     #[derive(DocumentedFieldsOpt)]
@@ -73,6 +87,23 @@ fn union_works() {
     );
 }
 
+#[test]
+fn const_lookup_works() {
+    #[derive(DocumentedFieldsOpt)]
+    #[allow(dead_code)]
+    struct Foo {
+        /// 1
+        first: i32,
+        second: i32,
+    }
+
+    const FIRST: Option<&str> = field_docs_opt_const::<Foo>("first");
+    const SECOND: Option<&str> = field_docs_opt_const::<Foo>("second");
+
+    assert_eq!(FIRST, Some("1"));
+    assert_eq!(SECOND, None);
+}
+
 #[cfg(feature = "customise")]
 mod test_customise {
     use documented::{DocumentedFieldsOpt, Error};
@@ -104,4 +135,87 @@ mod test_customise {
         );
         assert_eq!(Mission::get_field_docs("Touchdown"), Ok("Boom"));
     }
+
+    #[test]
+    fn flatten_works() {
+        #[derive(DocumentedFieldsOpt)]
+        #[allow(dead_code)]
+        struct Inner {
+            undocumented: bool,
+        }
+
+        #[derive(DocumentedFieldsOpt)]
+        #[allow(dead_code)]
+        struct Outer {
+            #[documented_fields(flatten)]
+            inner: Inner,
+        }
+
+        assert_eq!(
+            Outer::get_field_docs("inner"),
+            Err(Error::NoDocComments("inner".into()))
+        );
+        assert_eq!(
+            Outer::get_field_docs("inner.undocumented"),
+            Err(Error::NoDocComments("inner.undocumented".into()))
+        );
+    }
+
+    #[test]
+    fn default_from_type_works() {
+        use documented::DocumentedOpt;
+
+        /// Where the data lives.
+        #[derive(DocumentedOpt)]
+        #[allow(dead_code)]
+        struct DatabaseConfig {
+            url: String,
+        }
+
+        #[derive(DocumentedOpt)]
+        #[allow(dead_code)]
+        struct LogConfig {
+            level: u8,
+        }
+
+        #[derive(DocumentedFieldsOpt)]
+        #[documented_fields(default_from_type)]
+        #[allow(dead_code)]
+        struct AppConfig {
+            database: DatabaseConfig,
+            logger: LogConfig,
+        }
+
+        assert_eq!(AppConfig::FIELD_DOCS, [Some("Where the data lives."), None]);
+        assert_eq!(
+            AppConfig::get_field_docs("logger"),
+            Err(Error::NoDocComments("logger".into()))
+        );
+    }
+
+    #[test]
+    fn transparent_works() {
+        #[derive(DocumentedFieldsOpt)]
+        #[allow(dead_code)]
+        struct Settings {
+            /// Whether to use dark mode.
+            dark_mode: bool,
+            volume: u8,
+        }
+
+        #[derive(DocumentedFieldsOpt)]
+        #[documented_fields(transparent)]
+        #[allow(dead_code)]
+        struct UserSettings(Settings);
+
+        assert_eq!(
+            UserSettings::FIELD_DOCS,
+            [Some("Whether to use dark mode."), None]
+        );
+        assert_eq!(
+            UserSettings::get_field_docs("volume"),
+            Err(Error::NoDocComments("volume".into()))
+        );
+        assert_eq!(UserSettings::FIELDS, Settings::FIELDS);
+    }
 }
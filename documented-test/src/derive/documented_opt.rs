@@ -29,4 +29,24 @@ mod test_customise {
 
         assert_eq!(NiceFlight::DOCS, Some("Nice catch!"));
     }
+
+    #[test]
+    fn transparent_works() {
+        /// A user's unique identifier.
+        #[derive(DocumentedOpt)]
+        #[allow(dead_code)]
+        struct Id(u64);
+
+        #[derive(DocumentedOpt)]
+        #[allow(dead_code)]
+        struct Secret(u64);
+
+        #[derive(DocumentedOpt)]
+        #[documented(transparent)]
+        #[allow(dead_code)]
+        struct Wrapper<T>(T);
+
+        assert_eq!(Wrapper::<Id>::DOCS, Some("A user's unique identifier."));
+        assert_eq!(Wrapper::<Secret>::DOCS, None);
+    }
 }
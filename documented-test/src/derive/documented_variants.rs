@@ -1,4 +1,4 @@
-use documented::DocumentedVariants;
+use documented::{DocumentedVariants, Error};
 
 #[test]
 fn it_works() {
@@ -14,6 +14,153 @@ fn it_works() {
     assert_eq!(Foo::Second.get_variant_docs(), "2");
 }
 
+#[test]
+fn manual_impl_works() {
+    enum Foo {
+        First,
+    }
+    impl DocumentedVariants for Foo {
+        fn get_variant_docs(&self) -> &'static str {
+            match self {
+                Self::First => "1",
+            }
+        }
+    }
+
+    assert_eq!(Foo::First.get_variant_docs(), "1");
+    assert!(Foo::VARIANT_NAMES.is_empty());
+    assert!(Foo::get_variant_docs_by_name("First").is_err());
+    assert!(Foo::First.get_variant_field_docs("0").is_err());
+}
+
+#[test]
+fn lookup_by_name_works() {
+    #[allow(dead_code)]
+    #[derive(DocumentedVariants)]
+    enum Level {
+        /// Everything.
+        Trace,
+        /// Only the bad stuff.
+        Error(String),
+    }
+
+    assert_eq!(Level::VARIANT_NAMES, &["Trace", "Error"]);
+    assert_eq!(Level::get_variant_docs_by_name("Trace"), Ok("Everything."));
+    assert_eq!(
+        Level::get_variant_docs_by_name("Error"),
+        Ok("Only the bad stuff.")
+    );
+    assert_eq!(
+        Level::get_variant_docs_by_name("Debug"),
+        Err(Error::NoSuchField("Debug".into()))
+    );
+    let err = Level::get_variant_docs_by_name("Erorr").unwrap_err();
+    assert!(err
+        .to_string()
+        .ends_with(r#"Level"; did you mean "Error"?"#));
+}
+
+#[test]
+fn variant_field_docs_work() {
+    #[allow(dead_code)]
+    #[derive(DocumentedVariants)]
+    enum Command {
+        /// Deploy the thing.
+        Deploy {
+            /// Target environment.
+            env: String,
+            force: bool,
+        },
+        /// Roll back.
+        Rollback(
+            /// How many releases.
+            usize,
+        ),
+        /// Do nothing.
+        Idle,
+    }
+
+    let deploy = Command::Deploy { env: "prod".into(), force: true };
+    assert_eq!(
+        deploy.get_variant_field_docs("env"),
+        Ok("Target environment.")
+    );
+    assert_eq!(
+        deploy.get_variant_field_docs("force"),
+        Err(Error::NoDocComments("force".into()))
+    );
+    assert_eq!(
+        deploy.get_variant_field_docs("0"),
+        Err(Error::NoSuchField("0".into()))
+    );
+    assert_eq!(
+        Command::Rollback(1).get_variant_field_docs("0"),
+        Ok("How many releases.")
+    );
+    assert_eq!(
+        Command::Idle.get_variant_field_docs("env"),
+        Err(Error::NoSuchField("env".into()))
+    );
+
+    let err = deploy.get_variant_field_docs("froce").unwrap_err();
+    assert!(err
+        .to_string()
+        .ends_with(r#"Command"; did you mean "force"?"#));
+}
+
+#[test]
+fn const_accessors_work() {
+    #[allow(dead_code)]
+    #[derive(DocumentedVariants)]
+    enum Level {
+        /// Loud.
+        Error = 40,
+        /// Less loud.
+        Warn = 30,
+        /// Quiet.
+        Info,
+        /// Hush.
+        Debug = -1,
+    }
+
+    const WARN_DOCS: &str = Level::Warn.variant_docs_const();
+    const INFO_DOCS: Option<&str> = Level::docs_for_discriminant(31);
+
+    assert_eq!(WARN_DOCS, "Less loud.");
+    assert_eq!(INFO_DOCS, Some("Quiet."));
+    assert_eq!(
+        Level::VARIANT_DOCS,
+        ["Loud.", "Less loud.", "Quiet.", "Hush."]
+    );
+    assert_eq!(Level::docs_for_discriminant(40), Some("Loud."));
+    assert_eq!(Level::docs_for_discriminant(-1), Some("Hush."));
+    assert_eq!(Level::docs_for_discriminant(0), None);
+}
+
+#[test]
+fn doc_hidden_variants_are_skipped() {
+    #[allow(dead_code)]
+    #[derive(DocumentedVariants)]
+    enum Level {
+        /// Loud.
+        Error = 40,
+        /// Internal.
+        #[doc(hidden)]
+        Trace = 0,
+        /// Quiet.
+        Info = 20,
+    }
+
+    assert_eq!(Level::VARIANT_NAMES, &["Error", "Info"]);
+    assert_eq!(Level::VARIANT_DOCS, ["Loud.", "Quiet."]);
+    assert_eq!(Level::Trace.get_variant_docs(), "Internal.");
+    assert_eq!(Level::docs_for_discriminant(0), None);
+    assert_eq!(
+        Level::get_variant_docs_by_name("Trace"),
+        Err(Error::NoSuchField("Trace".into()))
+    );
+}
+
 #[test]
 fn works_on_adt_enums() {
     #[allow(dead_code)]
@@ -216,4 +363,176 @@ mod test_customise {
             "I think you're more prepared than Noah"
         );
     }
+
+    #[test]
+    fn rename_and_rename_all_work() {
+        #[derive(DocumentedVariants)]
+        #[documented_variants(rename_all = "kebab-case")]
+        #[allow(dead_code)]
+        enum LogLevel {
+            /// Chatty.
+            VeryVerbose,
+            /// Grumpy.
+            #[documented_variants(rename = "err")]
+            Error,
+            /// Shouty.
+            #[documented_variants(rename_all = "UPPERCASE")]
+            Warn,
+        }
+
+        assert_eq!(LogLevel::VARIANT_NAMES, &["very-verbose", "err", "WARN"]);
+        assert_eq!(
+            LogLevel::get_variant_docs_by_name("very-verbose"),
+            Ok("Chatty.")
+        );
+        assert_eq!(LogLevel::get_variant_docs_by_name("err"), Ok("Grumpy."));
+        assert_eq!(LogLevel::get_variant_docs_by_name("WARN"), Ok("Shouty."));
+        assert!(LogLevel::get_variant_docs_by_name("VeryVerbose").is_err());
+        // instance lookup is unaffected
+        assert_eq!(LogLevel::Error.get_variant_docs(), "Grumpy.");
+    }
+
+    #[test]
+    fn lookup_modes_work() {
+        use documented::Error;
+
+        macro_rules! test_lookup {
+            ($mode:literal) => {{
+                #[derive(DocumentedVariants)]
+                #[documented_variants(lookup = $mode)]
+                #[allow(dead_code)]
+                enum Foo {
+                    /// 1
+                    First,
+                    /// 2
+                    Second,
+                }
+
+                assert_eq!(Foo::get_variant_docs_by_name("First"), Ok("1"));
+                assert_eq!(Foo::get_variant_docs_by_name("Second"), Ok("2"));
+                assert_eq!(
+                    Foo::get_variant_docs_by_name("Third"),
+                    Err(Error::NoSuchField("Third".into()))
+                );
+            }};
+        }
+
+        test_lookup!("match");
+        test_lookup!("binary_search");
+        #[cfg(feature = "phf")]
+        test_lookup!("phf");
+    }
+
+    #[test]
+    fn skip_works() {
+        use documented::Error;
+
+        #[derive(DocumentedVariants)]
+        #[allow(dead_code)]
+        enum Command {
+            /// Deploy the thing.
+            Deploy { env: String },
+            /// Undocumented internals.
+            #[documented_variants(skip)]
+            Debug(
+                /// Some value.
+                u8,
+            ),
+            /// Shown anyway.
+            #[doc(hidden)]
+            #[documented_variants(skip = false)]
+            Trace,
+            #[documented_variants(skip, default = "Idling.")]
+            Idle,
+        }
+
+        assert_eq!(Command::VARIANT_NAMES, &["Deploy", "Trace"]);
+        assert_eq!(
+            Command::Debug(0).get_variant_docs(),
+            "Undocumented internals."
+        );
+        assert_eq!(Command::Idle.get_variant_docs(), "Idling.");
+        assert_eq!(Command::Trace.get_variant_docs(), "Shown anyway.");
+        assert_eq!(
+            Command::get_variant_docs_by_name("Debug"),
+            Err(Error::NoSuchField("Debug".into()))
+        );
+        assert_eq!(
+            Command::Debug(0).get_variant_field_docs("0"),
+            Err(Error::NoSuchField("0".into()))
+        );
+    }
+
+    #[test]
+    fn delegate_works() {
+        use documented::Documented;
+
+        /// A user signed in.
+        #[derive(Documented)]
+        #[allow(dead_code)]
+        struct LoginEvent {
+            user: String,
+        }
+
+        /// A user signed out.
+        #[derive(Documented)]
+        #[allow(dead_code)]
+        struct LogoutEvent;
+
+        #[derive(DocumentedVariants)]
+        #[allow(dead_code)]
+        enum Event {
+            #[documented_variants(delegate)]
+            Login(LoginEvent),
+            /// Ignored in favour of the payload.
+            #[documented_variants(delegate)]
+            Logout { event: LogoutEvent },
+            /// The server shut down.
+            Shutdown,
+        }
+
+        #[derive(DocumentedVariants)]
+        #[documented_variants(delegate)]
+        #[allow(dead_code)]
+        enum Wrapped<T> {
+            Event(T),
+            /// Something else.
+            #[documented_variants(delegate = false)]
+            Other(u8),
+        }
+
+        let login = Event::Login(LoginEvent { user: "ferris".into() });
+        assert_eq!(login.get_variant_docs(), "A user signed in.");
+        assert_eq!(
+            Event::Logout { event: LogoutEvent }.get_variant_docs(),
+            "A user signed out."
+        );
+        assert_eq!(Event::Shutdown.get_variant_docs(), "The server shut down.");
+        assert_eq!(
+            Event::get_variant_docs_by_name("Login"),
+            Ok("A user signed in.")
+        );
+        assert_eq!(
+            Wrapped::Event(LogoutEvent).get_variant_docs(),
+            "A user signed out."
+        );
+        assert_eq!(
+            Wrapped::<LogoutEvent>::Other(0).get_variant_docs(),
+            "Something else."
+        );
+    }
+}
+
+#[test]
+fn const_accessors_work_for_repr_u128() {
+    #[derive(DocumentedVariants)]
+    #[allow(dead_code)]
+    #[repr(u128)]
+    enum Big {
+        /// Big one.
+        A = u128::MAX,
+    }
+
+    assert_eq!(Big::VARIANT_DOCS, ["Big one."]);
+    assert_eq!(Big::A.variant_docs_const(), "Big one.");
 }
@@ -1,4 +1,4 @@
-use documented::DocumentedVariantsOpt;
+use documented::{DocumentedVariants, DocumentedVariantsOpt, Error};
 
 #[test]
 fn it_works() {
@@ -13,6 +13,66 @@ fn it_works() {
     assert_eq!(Foo::Second.get_variant_docs(), Some("2"));
 }
 
+#[test]
+fn manual_impl_works() {
+    enum Foo {
+        First,
+    }
+    impl DocumentedVariantsOpt for Foo {
+        fn get_variant_docs(&self) -> Option<&'static str> {
+            match self {
+                Self::First => Some("1"),
+            }
+        }
+    }
+
+    assert_eq!(Foo::First.get_variant_docs(), Some("1"));
+    assert!(Foo::VARIANT_NAMES.is_empty());
+    assert!(Foo::get_variant_docs_by_name("First").is_err());
+    assert!(Foo::First.get_variant_field_docs("0").is_err());
+}
+
+#[test]
+fn lookup_by_name_works() {
+    #[derive(DocumentedVariantsOpt)]
+    #[allow(dead_code)]
+    enum Foo {
+        First,
+        /// 2
+        Second,
+    }
+
+    assert_eq!(Foo::VARIANT_NAMES, &["First", "Second"]);
+    assert_eq!(
+        Foo::get_variant_docs_by_name("First"),
+        Err(Error::NoDocComments("First".into()))
+    );
+    assert_eq!(Foo::get_variant_docs_by_name("Second"), Ok("2"));
+    assert_eq!(
+        Foo::get_variant_docs_by_name("Third"),
+        Err(Error::NoSuchField("Third".into()))
+    );
+}
+
+#[test]
+fn can_derive_with_documented_variants() {
+    #[derive(DocumentedVariants, DocumentedVariantsOpt)]
+    #[allow(dead_code)]
+    enum Foo {
+        /// 1
+        First,
+        /// 2
+        Second,
+    }
+
+    assert_eq!(DocumentedVariants::get_variant_docs(&Foo::Second), "2");
+    assert_eq!(
+        DocumentedVariantsOpt::get_variant_docs(&Foo::First),
+        Some("1")
+    );
+    assert_eq!(Foo::VARIANT_DOCS, ["1", "2"]);
+}
+
 #[cfg(feature = "customise")]
 mod test_customise {
     use documented::DocumentedVariantsOpt;
@@ -41,4 +101,54 @@ mod test_customise {
         );
         assert_eq!(Dead::OJ.get_variant_docs(), None);
     }
+
+    #[test]
+    fn skip_works() {
+        #[derive(DocumentedVariantsOpt)]
+        #[allow(dead_code)]
+        enum Command {
+            /// Deploy the thing.
+            Deploy,
+            #[documented_variants(skip)]
+            Debug,
+            /// Internal.
+            #[doc(hidden)]
+            Trace,
+            #[documented_variants(skip, default = Some("Idling."))]
+            Idle,
+        }
+
+        assert_eq!(Command::VARIANT_NAMES, &["Deploy"]);
+        assert_eq!(Command::Debug.get_variant_docs(), None);
+        assert_eq!(Command::Trace.get_variant_docs(), Some("Internal."));
+        assert_eq!(Command::Idle.get_variant_docs(), Some("Idling."));
+    }
+
+    #[test]
+    fn delegate_works() {
+        use documented::DocumentedOpt;
+
+        /// A user signed in.
+        #[derive(DocumentedOpt)]
+        #[allow(dead_code)]
+        struct LoginEvent;
+
+        #[derive(DocumentedOpt)]
+        #[allow(dead_code)]
+        struct PingEvent;
+
+        #[derive(DocumentedVariantsOpt)]
+        #[documented_variants(delegate)]
+        #[allow(dead_code)]
+        enum Event {
+            Login(LoginEvent),
+            Ping(PingEvent),
+        }
+
+        assert_eq!(
+            Event::Login(LoginEvent).get_variant_docs(),
+            Some("A user signed in.")
+        );
+        assert_eq!(Event::Ping(PingEvent).get_variant_docs(), None);
+    }
 }
@@ -65,3 +65,40 @@ mod non_unique_options {
     //! struct NinetyNinePercentOfPeople;
     //! ```
 }
+
+#[cfg(feature = "customise")]
+mod invalid_transparent {
+    //! ```compile_fail
+    //! /// A user's unique identifier.
+    //! #[derive(documented::Documented)]
+    //! struct Id(u64);
+    //!
+    //! #[derive(documented::Documented)]
+    //! #[documented(transparent)]
+    //! struct UserId(Id, u8);
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[derive(documented::Documented)]
+    //! #[documented(transparent)]
+    //! enum UserId {
+    //!     Id(u64),
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! /// A user's unique identifier.
+    //! #[derive(documented::Documented)]
+    //! struct Id(u64);
+    //!
+    //! #[derive(documented::Documented)]
+    //! #[documented(transparent, trim = false)]
+    //! struct UserId(Id);
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[derive(documented::Documented)]
+    //! #[documented(transparent)]
+    //! struct UserId(u64);
+    //! ```
+}
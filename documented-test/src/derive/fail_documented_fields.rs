@@ -189,3 +189,331 @@ mod rename_on_container {
     //! }
     //! ```
 }
+
+#[cfg(feature = "customise")]
+mod flatten_on_non_field {
+    //! ```
+    //! use documented::DocumentedFields;
+    //!
+    //! #[derive(DocumentedFields)]
+    //! struct Move {
+    //!     /// Where it goes.
+    //!     square: String,
+    //! }
+    //!
+    //! #[derive(DocumentedFields)]
+    //! struct Game {
+    //!     /// The best one.
+    //!     #[documented_fields(flatten)]
+    //!     best_move: Move,
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! use documented::DocumentedFields;
+    //!
+    //! #[derive(DocumentedFields)]
+    //! struct Move {
+    //!     /// Where it goes.
+    //!     square: String,
+    //! }
+    //!
+    //! #[derive(DocumentedFields)]
+    //! #[documented_fields(flatten)]
+    //! struct Game {
+    //!     /// The best one.
+    //!     best_move: Move,
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! use documented::DocumentedFields;
+    //!
+    //! #[derive(DocumentedFields)]
+    //! enum Game {
+    //!     /// The best one.
+    //!     #[documented_fields(flatten)]
+    //!     BestMove(String),
+    //! }
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod invalid_lookup_mode {
+    //! ```
+    //! #[derive(documented::DocumentedFields)]
+    //! #[documented_fields(lookup = "match")]
+    //! struct Clock {
+    //!     /// Time left.
+    //!     left: u32,
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! #[documented_fields(lookup = "linear")]
+    //! struct Clock {
+    //!     /// Time left.
+    //!     left: u32,
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! struct Clock {
+    //!     /// Time left.
+    //!     #[documented_fields(lookup = "match")]
+    //!     left: u32,
+    //! }
+    //! ```
+}
+
+#[cfg(all(feature = "customise", not(feature = "phf")))]
+mod phf_lookup_without_feature {
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! #[documented_fields(lookup = "phf")]
+    //! struct Clock {
+    //!     /// Time left.
+    //!     left: u32,
+    //! }
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod duplicate_names {
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! #[documented_fields(lookup = "binary_search")]
+    //! struct Clock {
+    //!     /// Time left.
+    //!     left: u32,
+    //!     /// Time right.
+    //!     #[documented_fields(rename = "left")]
+    //!     right: u32,
+    //! }
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod misplaced_alias_options {
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! #[documented_fields(alias = "timer")]
+    //! struct Clock {
+    //!     /// Time left.
+    //!     left: u32,
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! struct Clock {
+    //!     /// Time left.
+    //!     #[documented_fields(case_insensitive)]
+    //!     left: u32,
+    //! }
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod duplicate_aliases {
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! struct Clock {
+    //!     /// Time left.
+    //!     #[documented_fields(alias = "right")]
+    //!     left: u32,
+    //!     /// Time right.
+    //!     right: u32,
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! #[documented_fields(case_insensitive)]
+    //! struct Clock {
+    //!     /// Time left.
+    //!     left: u32,
+    //!     /// Time left, but shouting.
+    //!     #[documented_fields(rename = "LEFT")]
+    //!     left_loud: u32,
+    //! }
+    //! ```
+}
+
+mod duplicate_doc_aliases {
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! struct Clock {
+    //!     /// Time left.
+    //!     #[doc(alias = "time")]
+    //!     left: u32,
+    //!     /// Time right.
+    //!     #[doc(alias = "time")]
+    //!     right: u32,
+    //! }
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod misplaced_skip_options {
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! #[documented_fields(skip)]
+    //! struct Clock {
+    //!     /// Time left.
+    //!     left: u32,
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! struct Clock {
+    //!     /// Time left.
+    //!     #[documented_fields(include = "pub")]
+    //!     left: u32,
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! #[documented_fields(include = "pub(crate)")]
+    //! struct Clock {
+    //!     /// Time left.
+    //!     pub left: u32,
+    //! }
+    //! ```
+}
+
+mod docs_of_skipped_field {
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! struct Clock {
+    //!     /// Time left.
+    //!     left: u32,
+    //!     /// Internal.
+    //!     #[doc(hidden)]
+    //!     ticks: u64,
+    //! }
+    //!
+    //! let _ = documented::docs_of!(Clock::ticks);
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod misplaced_default_from_type {
+    //! ```compile_fail
+    //! /// Where the data lives.
+    //! #[derive(documented::Documented)]
+    //! struct DatabaseConfig;
+    //!
+    //! #[derive(documented::DocumentedFields)]
+    //! #[documented_fields(default_from_type, default = "Unknown.")]
+    //! struct AppConfig {
+    //!     database: DatabaseConfig,
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! /// Where the data lives.
+    //! #[derive(documented::Documented)]
+    //! struct DatabaseConfig;
+    //!
+    //! #[derive(documented::DocumentedFields)]
+    //! struct AppConfig {
+    //!     #[documented_fields(default_from_type, default = "Unknown.")]
+    //!     database: DatabaseConfig,
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! #[documented_fields(default_from_type)]
+    //! enum Mode {
+    //!     Fast,
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! struct Undocumented;
+    //!
+    //! #[derive(documented::DocumentedFields)]
+    //! #[documented_fields(default_from_type)]
+    //! struct AppConfig {
+    //!     database: Undocumented,
+    //! }
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod invalid_transparent {
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! struct Settings {
+    //!     /// Whether to use dark mode.
+    //!     dark_mode: bool,
+    //! }
+    //!
+    //! #[derive(documented::DocumentedFields)]
+    //! #[documented_fields(transparent)]
+    //! struct UserSettings(Settings, u8);
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! struct Settings {
+    //!     /// Whether to use dark mode.
+    //!     dark_mode: bool,
+    //! }
+    //!
+    //! #[derive(documented::DocumentedFields)]
+    //! #[documented_fields(transparent, rename_all = "camelCase")]
+    //! struct UserSettings(Settings);
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! struct Settings {
+    //!     /// Whether to use dark mode.
+    //!     dark_mode: bool,
+    //! }
+    //!
+    //! #[derive(documented::DocumentedFields)]
+    //! #[documented_fields(transparent)]
+    //! struct UserSettings(#[documented_fields(rename = "settings")] Settings);
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! struct Settings {
+    //!     /// Whether to use dark mode.
+    //!     #[documented_fields(transparent)]
+    //!     dark_mode: bool,
+    //! }
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod misplaced_positional_names {
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! struct Point(
+    //!     /// Horizontal.
+    //!     #[documented_fields(positional_names)]
+    //!     f32,
+    //! );
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! #[documented_fields(positional_names)]
+    //! struct Point(
+    //!     /// Horizontal.
+    //!     f32,
+    //!     /// Horizontal, again.
+    //!     #[documented_fields(rename = "0")]
+    //!     f32,
+    //! );
+    //! ```
+}
@@ -54,50 +54,6 @@ mod non_applicable_options {
     //!     Adults,
     //! }
     //! ```
-    //!
-    //! ```compile_fail
-    //! #[derive(documented::DocumentedVariants)]
-    //! #[documented_variants(rename = "HalfOfYouKnow")]
-    //! enum AsYouAllKnow {
-    //!     /// None of them know it.
-    //!     Children,
-    //!     /// They know.
-    //!     Adults,
-    //! }
-    //! ```
-    //!
-    //! ```compile_fail
-    //! #[derive(documented::DocumentedVariants)]
-    //! enum AsYouAllKnow {
-    //!     /// None of them know it.
-    //!     #[documented_variants(rename = "HalfOfYouKnow")]
-    //!     Children,
-    //!     /// They know.
-    //!     Adults,
-    //! }
-    //! ```
-    //!
-    //! ```compile_fail
-    //! #[derive(documented::DocumentedVariants)]
-    //! #[documented_variants(rename_all = "snake_case")]
-    //! enum AsYouAllKnow {
-    //!     /// None of them know it.
-    //!     Children,
-    //!     /// They know.
-    //!     Adults,
-    //! }
-    //! ```
-    //!
-    //! ```compile_fail
-    //! #[derive(documented::DocumentedVariants)]
-    //! enum AsYouAllKnow {
-    //!     /// None of them know it.
-    //!     #[documented_variants(rename_all = "snake_case")]
-    //!     Children,
-    //!     /// They know.
-    //!     Adults,
-    //! }
-    //! ```
 }
 
 #[cfg(feature = "customise")]
@@ -168,3 +124,166 @@ mod non_unique_options {
     //! }
     //! ```
 }
+
+#[cfg(feature = "customise")]
+mod rename_on_container {
+    //! ```
+    //! use documented::DocumentedVariants;
+    //!
+    //! #[derive(DocumentedVariants)]
+    //! #[documented_variants(rename_all = "snake_case")]
+    //! enum AsYouAllKnow {
+    //!     /// None of them know it.
+    //!     #[documented_variants(rename = "HalfOfYouKnow")]
+    //!     Children,
+    //!     /// They know.
+    //!     Adults,
+    //! }
+    //!
+    //! assert!(AsYouAllKnow::get_variant_docs_by_name("HalfOfYouKnow").is_ok());
+    //! assert!(AsYouAllKnow::get_variant_docs_by_name("adults").is_ok());
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedVariants)]
+    //! #[documented_variants(rename = "HalfOfYouKnow")]
+    //! enum AsYouAllKnow {
+    //!     /// None of them know it.
+    //!     Children,
+    //!     /// They know.
+    //!     Adults,
+    //! }
+    //! ```
+}
+
+mod docs_for_discriminant_on_repr_u128 {
+    //! ```
+    //! #[derive(documented::DocumentedVariants)]
+    //! #[repr(u64)]
+    //! enum Size {
+    //!     /// The big one.
+    //!     Big = u64::MAX,
+    //! }
+    //!
+    //! assert_eq!(Size::docs_for_discriminant(u64::MAX.into()), Some("The big one."));
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedVariants)]
+    //! #[repr(u128)]
+    //! enum Size {
+    //!     /// The big one.
+    //!     Big = u128::MAX,
+    //! }
+    //!
+    //! Size::docs_for_discriminant(-1);
+    //! ```
+}
+
+mod const_accessors_on_adt_enum {
+    //! ```
+    //! #[derive(documented::DocumentedVariants)]
+    //! enum Piece {
+    //!     /// The one that matters.
+    //!     King,
+    //!     /// The one that moves weirdly.
+    //!     Knight,
+    //! }
+    //!
+    //! assert_eq!(Piece::VARIANT_DOCS.len(), 2);
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedVariants)]
+    //! enum Piece {
+    //!     /// The one that matters.
+    //!     King,
+    //!     /// The one that moves weirdly.
+    //!     Knight(u8),
+    //! }
+    //!
+    //! assert_eq!(Piece::VARIANT_DOCS.len(), 2);
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod skip_without_default {
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedVariants)]
+    //! enum Piece {
+    //!     /// The one that matters.
+    //!     King,
+    //!     #[documented_variants(skip)]
+    //!     Joker,
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedVariants)]
+    //! enum Piece {
+    //!     /// The one that matters.
+    //!     King,
+    //!     #[doc(hidden)]
+    //!     Joker,
+    //! }
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod misplaced_skip_options {
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedVariants)]
+    //! #[documented_variants(skip)]
+    //! enum Piece {
+    //!     /// The one that matters.
+    //!     King,
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedVariants)]
+    //! #[documented_variants(include = "pub")]
+    //! enum Piece {
+    //!     /// The one that matters.
+    //!     King,
+    //! }
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod invalid_delegate {
+    //! ```compile_fail
+    //! use documented::DocumentedVariants;
+    //!
+    //! #[derive(DocumentedVariants)]
+    //! enum Event {
+    //!     /// The server shut down.
+    //!     #[documented_variants(delegate)]
+    //!     Shutdown,
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! use documented::DocumentedVariants;
+    //!
+    //! /// A user signed in.
+    //! #[derive(documented::Documented)]
+    //! struct LoginEvent;
+    //!
+    //! #[derive(DocumentedVariants)]
+    //! enum Event {
+    //!     #[documented_variants(delegate)]
+    //!     Login(LoginEvent, u8),
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! use documented::DocumentedVariants;
+    //!
+    //! #[derive(DocumentedVariants)]
+    //! enum Event {
+    //!     #[documented_variants(delegate)]
+    //!     Login(u64),
+    //! }
+    //! ```
+}
@@ -0,0 +1,16 @@
+//! Tests for function-like macros.
+//!
+//! `fail_*` modules contains examples that should fail to compile,
+//! tested with doctest's `compile_fail` feature.
+//! Some `compile_fail` examples may be preceded by a "base" working example,
+//! which is useful for preventing some false negatives.
+//! I.e. the `compile_fail` examples fail, but it's due to other errors
+//! that we are not trying to test for.
+
+#[cfg(test)]
+mod docs_of;
+#[cfg(test)]
+mod file_docs;
+
+mod fail_docs_of;
+mod fail_file_docs;
@@ -0,0 +1,143 @@
+use documented::{
+    docs_of, field_docs_const, field_docs_opt_const, DocumentedFields, DocumentedFieldsOpt,
+};
+
+#[test]
+fn struct_works() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Config {
+        /// The port to listen on.
+        port: u16,
+        /// The host to bind to.
+        r#type: String,
+    }
+
+    assert_eq!(docs_of!(Config::port), "The port to listen on.");
+    assert_eq!(docs_of!(Config::r#type), "The host to bind to.");
+}
+
+#[test]
+fn enum_works() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    enum Mode {
+        /// Go fast.
+        Fast,
+        /// Go slow.
+        Slow { speed: u8 },
+    }
+
+    assert_eq!(docs_of!(Mode::Fast), "Go fast.");
+    assert_eq!(docs_of!(Mode::Slow), "Go slow.");
+}
+
+#[test]
+fn union_works() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    union Number {
+        /// An integer.
+        int: u32,
+        /// A float.
+        float: f32,
+    }
+
+    assert_eq!(docs_of!(Number::float), "A float.");
+}
+
+#[test]
+fn tuple_struct_works() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Pair(
+        /// The first one.
+        u8,
+        /// The second one.
+        u8,
+    );
+
+    assert_eq!(docs_of!(Pair::0), "The first one.");
+    assert_eq!(docs_of!(Pair::1), "The second one.");
+}
+
+#[test]
+fn generic_type_works() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Wrapper<T> {
+        /// The wrapped value.
+        inner: T,
+    }
+
+    assert_eq!(docs_of!(Wrapper::<u8>::inner), "The wrapped value.");
+}
+
+#[test]
+fn nested_path_works() {
+    mod config {
+        #[derive(documented::DocumentedFields)]
+        #[allow(dead_code)]
+        pub struct Config {
+            /// The port to listen on.
+            pub port: u16,
+        }
+    }
+
+    assert_eq!(docs_of!(config::Config::port), "The port to listen on.");
+}
+
+#[test]
+fn const_works() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Config {
+        /// The port to listen on.
+        port: u16,
+    }
+
+    const PORT_DOCS: &str = docs_of!(Config::port);
+    assert_eq!(PORT_DOCS, "The port to listen on.");
+}
+
+#[test]
+fn works_with_both_derives() {
+    #[derive(DocumentedFields, DocumentedFieldsOpt)]
+    #[allow(dead_code)]
+    struct Config {
+        /// The port to listen on.
+        port: u16,
+    }
+
+    assert_eq!(docs_of!(Config::port), "The port to listen on.");
+    assert_eq!(
+        <Config as DocumentedFieldsOpt>::FIELD_DOCS,
+        [Some("The port to listen on.")]
+    );
+    assert_eq!(
+        field_docs_const::<Config>("port"),
+        field_docs_opt_const::<Config>("port")
+    );
+}
+
+#[cfg(feature = "customise")]
+mod test_customise {
+    use documented::{docs_of, DocumentedFields};
+
+    #[test]
+    fn renamed_field_uses_rust_name() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(rename_all = "kebab-case")]
+        #[allow(dead_code)]
+        struct Config {
+            /// The port to listen on.
+            listen_port: u16,
+        }
+
+        assert_eq!(docs_of!(Config::listen_port), "The port to listen on.");
+        assert_eq!(
+            Config::get_field_docs("listen-port"),
+            Ok("The port to listen on.")
+        );
+    }
+}
@@ -0,0 +1,52 @@
+mod no_such_field {
+    //! ```
+    //! #[derive(documented::DocumentedFields)]
+    //! struct Config {
+    //!     /// The port to listen on.
+    //!     port: u16,
+    //! }
+    //!
+    //! let _ = documented::docs_of!(Config::port);
+    //! ```
+    //!
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! struct Config {
+    //!     /// The port to listen on.
+    //!     port: u16,
+    //! }
+    //!
+    //! let _ = documented::docs_of!(Config::prot);
+    //! ```
+}
+
+mod no_such_variant {
+    //! ```compile_fail
+    //! #[derive(documented::DocumentedFields)]
+    //! enum Mode {
+    //!     /// Go fast.
+    //!     Fast,
+    //! }
+    //!
+    //! let _ = documented::docs_of!(Mode::Slow);
+    //! ```
+}
+
+mod no_derive {
+    //! ```compile_fail
+    //! /// A config.
+    //! #[derive(documented::Documented)]
+    //! struct Config {
+    //!     /// The port to listen on.
+    //!     port: u16,
+    //! }
+    //!
+    //! let _ = documented::docs_of!(Config::port);
+    //! ```
+}
+
+mod no_type {
+    //! ```compile_fail
+    //! let _ = documented::docs_of!(port);
+    //! ```
+}
@@ -0,0 +1,38 @@
+mod missing_docs {
+    //! ```
+    //! documented::module_docs!("src/fn_like.rs");
+    //! ```
+    //!
+    //! ```compile_fail
+    //! documented::module_docs!("src/derive/documented.rs");
+    //! ```
+}
+
+mod missing_file {
+    //! ```compile_fail
+    //! documented::module_docs!("src/does_not_exist.rs");
+    //! ```
+}
+
+mod missing_path {
+    //! ```compile_fail
+    //! documented::module_docs!();
+    //! ```
+}
+
+mod path_for_crate_docs {
+    //! ```compile_fail
+    //! documented::crate_docs!("src/lib.rs");
+    //! ```
+}
+
+#[cfg(feature = "customise")]
+mod non_applicable_options {
+    //! ```
+    //! documented::crate_docs!(trim = false);
+    //! ```
+    //!
+    //! ```compile_fail
+    //! documented::crate_docs!(vis = pub);
+    //! ```
+}
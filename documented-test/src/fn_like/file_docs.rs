@@ -0,0 +1,65 @@
+//! Tests for `crate_docs` and `module_docs`.
+//!
+#![doc = include_str!("../derive/external_docs.md")]
+
+use documented::{crate_docs, module_docs};
+
+#[test]
+fn crate_docs_works() {
+    assert_eq!(
+        crate_docs!(),
+        "Tests for the macros of `documented`.\n\nOrganised by the kind of macro being tested."
+    );
+}
+
+#[test]
+fn module_docs_works() {
+    assert_eq!(
+        module_docs!("src/fn_like.rs").lines().next(),
+        Some("Tests for function-like macros.")
+    );
+}
+
+#[test]
+fn module_docs_evaluates_include_str() {
+    assert_eq!(
+        module_docs!("src/fn_like/file_docs.rs"),
+        "Tests for `crate_docs` and `module_docs`.\n\nKept in an external file.\nIndented for no good reason.\n"
+    );
+}
+
+#[test]
+fn docs_are_literals() {
+    const ABOUT: &str = crate_docs!();
+
+    assert!(ABOUT.starts_with("Tests for the macros"));
+}
+
+#[cfg(feature = "customise")]
+mod test_customise {
+    use documented::{crate_docs, module_docs};
+
+    #[test]
+    fn trim_false_works() {
+        assert_eq!(
+            crate_docs!(trim = false),
+            " Tests for the macros of `documented`.\n\n Organised by the kind of macro being tested."
+        );
+    }
+
+    #[test]
+    fn trim_dedent_works() {
+        assert_eq!(
+            module_docs!("src/fn_like/file_docs.rs", trim = "dedent"),
+            "Tests for `crate_docs` and `module_docs`.\n\n    Kept in an external file.\n    Indented for no good reason."
+        );
+    }
+
+    #[test]
+    fn default_works() {
+        assert_eq!(
+            module_docs!("src/derive/documented.rs", default = "None."),
+            "None."
+        );
+    }
+}
@@ -1,2 +1,7 @@
+//! Tests for the macros of `documented`.
+//!
+//! Organised by the kind of macro being tested.
+
 mod attr;
 mod derive;
+mod fn_like;
@@ -1,12 +1,32 @@
 //! # Caveats
 //!
-//! ## No line-trimming for macro-inserted doc comments
+//! ## No line-trimming for doc comments inserted by custom macros
 //!
-//! The line-trimming feature does not work for doc comments inserted by macros:
+//! The built-in macros `concat!`, `include_str!` and `env!` are evaluated by
+//! the procedural macros of `documented` themselves, so line-trimming works
+//! for them just like for literals:
 //!
 //! ```
 //! #[doc = concat!("  line 1\n", "    line 2")]
 //! #[derive(documented::Documented)]
+//! struct Fine;
+//!
+//! # use documented::Documented;
+//! assert_eq!(Fine::DOCS, "line 1\nline 2");
+//! ```
+//!
+//! However, the line-trimming feature does not work for doc comments inserted
+//! by any other macro:
+//!
+//! ```
+//! macro_rules! lines {
+//!     () => {
+//!         "  line 1\n    line 2"
+//!     };
+//! }
+//!
+//! #[doc = lines!()]
+//! #[derive(documented::Documented)]
 //! struct Terrible;
 //!
 //! # use documented::Documented;
@@ -14,18 +34,27 @@
 //! assert_eq!(Terrible::DOCS, "  line 1\n    line 2");
 //! ```
 //!
-//! This is because the expansion of your macro invocation
-//! (e.g. `concat!`, `include_str!`, etc.) is not visible from the perspective
-//! of the procedural macros of `documented`. Therefore it is not possible
-//! (or rather, not practical) to do any post-processing on the text contents.
+//! This is because the expansion of such a macro invocation is not visible
+//! from the perspective of the procedural macros of `documented`. Therefore it
+//! is not possible (or rather, not practical) to do any post-processing on the
+//! text contents.
+//!
+//! The same applies to built-in macros that cannot be evaluated ahead of the
+//! compiler, e.g. `include_str!` of a file that cannot be located.
 //!
 //! Note that If an item has multiple `#[doc = ...]` attributes and only
-//! a subset of them use the macro instead of literal form, line-trimming
-//! will still work for the literal form attributes:
+//! a subset of them use such macros, line-trimming will still work for the
+//! other attributes:
 //!
 //! ```
+//! macro_rules! lines {
+//!     () => {
+//!         "  line 2\n    line 3"
+//!     };
+//! }
+//!
 //! ///     line 1
-//! #[doc = concat!("  line 2\n", "    line 3")]
+//! #[doc = lines!()]
 //! #[doc = "line 4    "]
 //! #[derive(documented::Documented)]
 //! struct Terrible;
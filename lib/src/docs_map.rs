@@ -0,0 +1,65 @@
+//! A name-keyed lookup of doc comments, for items that do not map onto a
+//! single type.
+
+use core::fmt;
+
+/// A static map from names to doc comments, in order of declaration.
+///
+/// Instances of this type are generated by the attribute macros of this crate,
+/// e.g. [`macro@documented_impl`](crate::documented_impl),
+/// [`macro@documented_trait`](crate::documented_trait),
+/// [`macro@documented_module`](crate::documented_module) and
+/// [`macro@param_docs`](crate::param_docs).
+#[derive(Clone, Copy)]
+pub struct DocsMap {
+    entries: &'static [(&'static str, &'static str)],
+    index_of: fn(&str) -> Option<usize>,
+}
+impl DocsMap {
+    /// Construct a new map from its entries and a function that looks up the
+    /// index of an entry by name.
+    ///
+    /// Only meant to be used by the macros of this crate.
+    #[doc(hidden)]
+    pub const fn __new(
+        entries: &'static [(&'static str, &'static str)],
+        index_of: fn(&str) -> Option<usize>,
+    ) -> Self {
+        Self { entries, index_of }
+    }
+
+    /// Get the doc comments of an item by name.
+    pub fn get<T: AsRef<str>>(&self, name: T) -> Option<&'static str> {
+        (self.index_of)(name.as_ref()).map(|i| self.entries[i].1)
+    }
+
+    /// Whether an item of the given name is in this map.
+    pub fn contains<T: AsRef<str>>(&self, name: T) -> bool {
+        (self.index_of)(name.as_ref()).is_some()
+    }
+
+    /// All names and doc comments in this map, in order of declaration.
+    pub const fn entries(&self) -> &'static [(&'static str, &'static str)] {
+        self.entries
+    }
+
+    /// Iterate over all names in this map, in order of declaration.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + Clone {
+        self.entries.iter().map(|&(name, _)| name)
+    }
+
+    /// The number of items in this map.
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether this map contains no items.
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+impl fmt::Debug for DocsMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.entries.iter().copied()).finish()
+    }
+}
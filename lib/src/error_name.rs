@@ -0,0 +1,250 @@
+//! The name carried by errors.
+//!
+//! With `std`, the name is kept in full in a `String`. Without it, the name is
+//! stored inline so that errors can be created without allocating, at the
+//! cost of truncating long names.
+
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+use crate::Suggestions;
+
+/// The name (or dotted path) an [`Error`](crate::Error) refers to.
+///
+/// With the `std` feature, the name is kept in full. Without it, names longer
+/// than 48 bytes are truncated at a char boundary, which is indicated by
+/// [`Self::is_truncated`] and a trailing `...` when displayed.
+///
+/// For [`Error::NoSuchField`](crate::Error::NoSuchField) returned by the
+/// lookups of this crate, this also carries the [type](Self::type_name) that
+/// was searched and [suggestions](Self::suggestions) of similar names.
+/// For a dotted path, the type is the one the lookup started from, and the
+/// suggestions are for the segment that does not exist, which follows the
+/// [parent path](Self::parent_path).
+/// These details are ignored by comparisons and hashing, so that errors can
+/// still be compared against e.g. `Error::NoSuchField("prot".into())`.
+///
+/// ```rust
+/// # use documented::ErrorName;
+/// let name = ErrorName::from("prot");
+/// assert_eq!(name, "prot");
+/// assert_eq!(name.len(), 4);
+/// assert!(!name.is_truncated());
+///
+/// let long = ErrorName::from("a.very.long.dotted.path.that.does.not.fit.in.48.bytes");
+/// # #[cfg(feature = "std")]
+/// assert_eq!(long, "a.very.long.dotted.path.that.does.not.fit.in.48.bytes");
+/// # #[cfg(not(feature = "std"))]
+/// assert!(long.is_truncated() && long.to_string().ends_with("..."));
+/// ```
+#[derive(Clone)]
+pub struct ErrorName {
+    name: Name,
+    type_name: Option<&'static str>,
+    suggestions: Suggestions,
+}
+impl ErrorName {
+    /// The name as a string slice.
+    pub fn as_str(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Whether the name was too long to be stored in full.
+    ///
+    /// This is always `false` with the `std` feature.
+    pub fn is_truncated(&self) -> bool {
+        self.name.is_truncated()
+    }
+
+    /// The part of the dotted path before the segment that does not exist,
+    /// including the trailing `.`, e.g. `inner.` for `inner.valeu`.
+    ///
+    /// This is empty if the first segment does not exist, or, without the
+    /// `std` feature, if the parent path does not fit.
+    pub fn parent_path(&self) -> &str {
+        self.name.parent_path()
+    }
+
+    /// The name of the type that was searched, as given by
+    /// [`core::any::type_name`], if known.
+    pub fn type_name(&self) -> Option<&'static str> {
+        self.type_name
+    }
+
+    /// The known names closest to the segment that does not exist, best match
+    /// first.
+    pub fn suggestions(&self) -> &Suggestions {
+        &self.suggestions
+    }
+
+    /// Attach the details of a failed lookup in `T`.
+    pub(crate) fn with_details<T: ?Sized>(self, suggestions: Suggestions) -> Self {
+        Self {
+            type_name: Some(core::any::type_name::<T>()),
+            suggestions,
+            ..self
+        }
+    }
+
+    /// Report this name as part of a lookup of `path` in `T`, where
+    /// `parent_len` bytes of `path` precede the name originally looked up.
+    pub(crate) fn nested_in<T: ?Sized>(self, path: &str, parent_len: usize) -> Self {
+        Self {
+            name: Name::new(path, parent_len.saturating_add(self.name.parent_len())),
+            type_name: self.type_name.map(|_| core::any::type_name::<T>()),
+            ..self
+        }
+    }
+}
+impl From<&str> for ErrorName {
+    fn from(name: &str) -> Self {
+        Self {
+            name: Name::new(name, 0),
+            type_name: None,
+            suggestions: Suggestions::default(),
+        }
+    }
+}
+impl Deref for ErrorName {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+impl AsRef<str> for ErrorName {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl PartialEq for ErrorName {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str() && self.is_truncated() == other.is_truncated()
+    }
+}
+impl Eq for ErrorName {}
+impl Hash for ErrorName {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+        self.is_truncated().hash(state);
+    }
+}
+impl PartialEq<str> for ErrorName {
+    fn eq(&self, other: &str) -> bool {
+        !self.is_truncated() && self.as_str() == other
+    }
+}
+impl PartialEq<&str> for ErrorName {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+impl fmt::Display for ErrorName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())?;
+        if self.is_truncated() {
+            f.write_str("...")?;
+        }
+        Ok(())
+    }
+}
+impl fmt::Debug for ErrorName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)?;
+        if self.is_truncated() {
+            f.write_str("...")?;
+        }
+        Ok(())
+    }
+}
+
+/// The storage of the name itself.
+#[cfg(feature = "std")]
+#[derive(Clone)]
+struct Name {
+    name: std::string::String,
+    parent_len: usize,
+}
+#[cfg(feature = "std")]
+impl Name {
+    fn new(name: &str, parent_len: usize) -> Self {
+        Self {
+            name: name.into(),
+            parent_len: parent_len.min(name.len()),
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        &self.name
+    }
+
+    fn is_truncated(&self) -> bool {
+        false
+    }
+
+    fn parent_len(&self) -> usize {
+        self.parent_len
+    }
+
+    fn parent_path(&self) -> &str {
+        self.name.get(..self.parent_len).unwrap_or_default()
+    }
+}
+
+/// The maximum length of a name stored inline, in bytes.
+///
+/// Together with the details, this keeps [`Error`](crate::Error) under the
+/// size Clippy considers too large to return.
+#[cfg(not(feature = "std"))]
+const CAPACITY: usize = 48;
+
+/// The storage of the name itself.
+#[cfg(not(feature = "std"))]
+#[derive(Clone)]
+struct Name {
+    buf: [u8; CAPACITY],
+    len: u8,
+    truncated: bool,
+    /// `u8::MAX` if the parent path does not fit.
+    parent_len: u8,
+}
+#[cfg(not(feature = "std"))]
+impl Name {
+    fn new(name: &str, parent_len: usize) -> Self {
+        let mut len = name.len().min(CAPACITY);
+        while !name.is_char_boundary(len) {
+            len -= 1;
+        }
+        let mut buf = [0; CAPACITY];
+        buf[..len].copy_from_slice(&name.as_bytes()[..len]);
+        Self {
+            buf,
+            len: len as u8,
+            truncated: len < name.len(),
+            parent_len: if parent_len <= len { parent_len as u8 } else { u8::MAX },
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..usize::from(self.len)]).unwrap_or_default()
+    }
+
+    fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// `usize::MAX` if the parent path does not fit.
+    fn parent_len(&self) -> usize {
+        match self.parent_len {
+            u8::MAX => usize::MAX,
+            len => usize::from(len),
+        }
+    }
+
+    fn parent_path(&self) -> &str {
+        self.as_str().get(..self.parent_len()).unwrap_or_default()
+    }
+}
@@ -1,15 +1,75 @@
-#![doc = include_str!(concat!("../", std::env!("CARGO_PKG_README")))]
+#![doc = include_str!(concat!("../", core::env!("CARGO_PKG_README")))]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod _caveats;
+mod docs_map;
+mod error_name;
+mod lookup;
+mod parts;
+mod suggest;
 
 pub use documented_macros::{
-    docs_const, Documented, DocumentedFields, DocumentedFieldsOpt, DocumentedOpt,
+    auto, crate_docs, docs_const, docs_of, documented_impl, documented_module, documented_trait,
+    module_docs, param_docs, Documented, DocumentedFields, DocumentedFieldsOpt, DocumentedOpt,
     DocumentedVariants, DocumentedVariantsOpt,
 };
 
+pub use docs_map::DocsMap;
+pub use error_name::ErrorName;
+pub use parts::{Section, Sections};
+pub use suggest::{Suggestions, SuggestionsIter};
+
+#[cfg(feature = "phf")]
 #[doc(hidden)]
 pub use phf as _private_phf_reexport_for_macro;
 
+/// String equality that can be evaluated in const context.
+const fn const_str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// ASCII case-insensitive string equality that can be evaluated in const
+/// context.
+const fn const_str_eq_ignore_ascii_case(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if !a[i].eq_ignore_ascii_case(&b[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Write `s` with ASCII letters lowercased into `buf`, without allocating.
+///
+/// Returns `None` if `s` does not fit. The macros of this crate make `buf` as
+/// long as the longest known name, so this means that `s` matches none of them.
+///
+/// Only meant to be used by the macros of this crate.
+#[doc(hidden)]
+pub fn _private_ascii_lowercase<'a>(s: &str, buf: &'a mut [u8]) -> Option<&'a str> {
+    let buf = buf.get_mut(..s.len())?;
+    buf.copy_from_slice(s.as_bytes());
+    buf.make_ascii_lowercase();
+    core::str::from_utf8(buf).ok()
+}
+
 /// Adds an associated constant [`DOCS`](Self::DOCS) on your type containing its
 /// documentation, allowing you to access its documentation at runtime.
 ///
@@ -22,6 +82,66 @@ pub trait Documented {
     const DOCS: &'static str;
 }
 
+/// Provides structured access to the documentation of a [`Documented`] type,
+/// i.e. its summary, its body, and its sections.
+///
+/// This trait is implemented for every type that implements [`Documented`],
+/// so there is nothing to derive.
+///
+/// ```rust
+/// use documented::{Documented, DocumentedParts};
+///
+/// /// Castles queenside.
+/// ///
+/// /// Only when it is a bad idea.
+/// ///
+/// /// # Examples
+/// ///
+/// /// O-O-O.
+/// ///
+/// /// # Errors
+/// ///
+/// /// When the king has moved.
+/// #[derive(Documented)]
+/// struct LongCastle;
+///
+/// assert_eq!(LongCastle::SUMMARY, "Castles queenside.");
+/// assert!(LongCastle::BODY.starts_with("Only when it is a bad idea.\n\n# Examples"));
+/// assert_eq!(LongCastle::get_section("Examples"), Some("O-O-O."));
+/// assert_eq!(LongCastle::get_section("Errors"), Some("When the king has moved."));
+/// assert_eq!(LongCastle::get_section("Safety"), None);
+/// ```
+pub trait DocumentedParts: Documented {
+    /// The summary of the documentation, i.e. its first paragraph.
+    const SUMMARY: &'static str;
+    /// The rest of the documentation after the summary.
+    const BODY: &'static str;
+
+    /// Iterate over the sections of the documentation, i.e. the parts headed
+    /// by Markdown headings such as `# Examples`.
+    ///
+    /// Headings inside fenced code blocks are ignored.
+    fn sections() -> Sections {
+        Sections::new(Self::DOCS)
+    }
+
+    /// Get the content of the first section with the given heading, e.g.
+    /// `"Examples"` for `# Examples`.
+    fn get_section<T: AsRef<str>>(heading: T) -> Option<&'static str> {
+        let heading = heading.as_ref();
+        Self::sections()
+            .find(|section| section.heading == heading)
+            .map(|section| section.content)
+    }
+}
+impl<T> DocumentedParts for T
+where
+    T: Documented + ?Sized,
+{
+    const SUMMARY: &'static str = parts::split_summary(T::DOCS).0;
+    const BODY: &'static str = parts::split_summary(T::DOCS).1;
+}
+
 /// The optional variant of [`Documented`].
 pub trait DocumentedOpt {
     /// The static doc comments on this type.
@@ -39,26 +159,64 @@ pub trait DocumentedOpt {
 /// This trait and associated derive macro works on structs, enums, and unions.
 /// For enums, you may find [`DocumentedVariants`] more ergonomic to use.
 ///
+/// To access the documentation of a known field with compile-time checking,
+/// use [`docs_of!`].
+///
 /// For how to use the derive macro, see [`macro@DocumentedFields`].
 pub trait DocumentedFields {
     /// The static doc comments on each field or variant of this type, indexed
     /// by field/variant order.
+    ///
+    /// Only direct fields are listed; the fields of flattened fields are not.
+    /// Neither are [skipped](macro@DocumentedFields#8-leave-out-fields) fields,
+    /// so the indices only count the fields that are listed.
     const FIELD_DOCS: &'static [&'static str];
     /// Field names, as accepted by [`Self::get_field_docs`].
     ///
     /// Note that anonymous fields (i.e. fields in tuple structs), unless they
-    /// have [a custom name set](macro@DocumentedFields#2-set-a-custom-name-for-a-specific-field-for-get_field_docs-like-so),
+    /// have [a custom name set](macro@DocumentedFields#2-set-a-custom-name-for-a-specific-field-for-get_field_docs-like-so)
+    /// or are [named by position](macro@DocumentedFields#11-name-anonymous-fields-by-position),
     /// will be omitted from `FIELD_NAMES`. This means that in such cases, the
     /// indices of `FIELD_NAMES` will be misaligned with that of `FIELD_DOCS`.
     ///
-    /// It is therefore recommended to use [`Self::get_field_docs`] rather than
-    /// the index to lookup the corresponding documentation.
+    /// It is therefore recommended to use [`FIELDS`](Self::FIELDS) or
+    /// [`Self::get_field_docs`] rather than the index to lookup the
+    /// corresponding documentation.
     const FIELD_NAMES: &'static [&'static str];
+    /// The name and doc comments of each field or variant of this type,
+    /// indexed by field/variant order.
+    ///
+    /// Unlike [`FIELD_NAMES`](Self::FIELD_NAMES), this is always aligned with
+    /// [`FIELD_DOCS`](Self::FIELD_DOCS). Anonymous fields without a name have
+    /// `None` as their name.
+    ///
+    /// Derived implementations always fill this in. Hand-written ones that
+    /// leave it empty are not found by [`field_docs_const`].
+    const FIELDS: &'static [(Option<&'static str>, &'static str)] = &[];
+    /// The aliases of each field or variant, indexed by field/variant order.
+    ///
+    /// These are declared with [`alias`](macro@DocumentedFields#7-accept-aliases-and-ignore-case-in-get_field_docs)
+    /// or `#[doc(alias = "...")]`, and are accepted by [`Self::get_field_docs`]
+    /// in addition to [`FIELD_NAMES`](Self::FIELD_NAMES).
+    const FIELD_ALIASES: &'static [&'static [&'static str]] = &[];
+
+    /// Constant internally used by `documented`.
+    #[doc(hidden)]
+    const __DOCUMENTED_CASE_INSENSITIVE: bool = false;
 
     /// Method internally used by `documented`.
     #[doc(hidden)]
     fn __documented_get_index<T: AsRef<str>>(field_name: T) -> Option<usize>;
 
+    /// Method internally used by `documented`.
+    #[doc(hidden)]
+    fn __documented_get_nested_field_docs(
+        _index: usize,
+        _field_path: &str,
+    ) -> Option<Result<&'static str, Error>> {
+        None
+    }
+
     /// Get a field's documentation using its name.
     ///
     /// Note that for structs with anonymous fields (i.e. tuple structs), this
@@ -66,12 +224,26 @@ pub trait DocumentedFields {
     /// case, you can either:
     ///
     /// 1. use [`FIELD_DOCS`](Self::FIELD_DOCS) directly instead;
-    /// 2. [set a custom name](macro@DocumentedFields#2-set-a-custom-name-for-a-specific-field-for-get_field_docs-like-so) for the anonymous field.
+    /// 2. [set a custom name](macro@DocumentedFields#2-set-a-custom-name-for-a-specific-field-for-get_field_docs-like-so) for the anonymous field;
+    /// 3. [name anonymous fields by position](macro@DocumentedFields#11-name-anonymous-fields-by-position), e.g. `"0"`.
+    ///
+    /// Fields of [flattened](macro@DocumentedFields#5-flatten-a-fields-own-fields-into-dotted-paths)
+    /// fields can be looked up using a dotted path, e.g. `"server.tls.cert_path"`.
+    /// The same goes for the fields of enum variants, e.g. `"Deploy.env"`.
+    ///
+    /// If the field does not exist, the returned [`Error::NoSuchField`]
+    /// suggests similarly named fields.
     fn get_field_docs<T: AsRef<str>>(field_name: T) -> Result<&'static str, Error> {
         let field_name = field_name.as_ref();
-        let index = Self::__documented_get_index(field_name)
-            .ok_or_else(|| Error::NoSuchField(field_name.into()))?;
-        Ok(Self::FIELD_DOCS[index])
+        match Self::__documented_get_index(field_name) {
+            Some(index) => Ok(Self::FIELD_DOCS[index]),
+            None => get_nested_field_docs::<Self>(
+                field_name,
+                Self::FIELD_NAMES,
+                |name| Self::__documented_get_index(name),
+                Self::__documented_get_nested_field_docs,
+            ),
+        }
     }
 }
 
@@ -79,22 +251,57 @@ pub trait DocumentedFields {
 pub trait DocumentedFieldsOpt {
     /// The static doc comments on each field or variant of this type, indexed
     /// by field/variant order.
+    ///
+    /// Only direct fields are listed; the fields of flattened fields are not.
+    /// Neither are [skipped](macro@DocumentedFields#8-leave-out-fields) fields,
+    /// so the indices only count the fields that are listed.
     const FIELD_DOCS: &'static [Option<&'static str>];
     /// Field names, as accepted by [`Self::get_field_docs`].
     ///
     /// Note that anonymous fields (i.e. fields in tuple structs), unless they
-    /// have [a custom name set](macro@DocumentedFields#2-set-a-custom-name-for-a-specific-field-for-get_field_docs-like-so),
+    /// have [a custom name set](macro@DocumentedFields#2-set-a-custom-name-for-a-specific-field-for-get_field_docs-like-so)
+    /// or are [named by position](macro@DocumentedFields#11-name-anonymous-fields-by-position),
     /// will be omitted from `FIELD_NAMES`. This means that in such cases, the
     /// indices of `FIELD_NAMES` will be misaligned with that of `FIELD_DOCS`.
     ///
-    /// It is therefore recommended to use [`Self::get_field_docs`] rather than
-    /// the index to lookup the corresponding documentation.
+    /// It is therefore recommended to use [`FIELDS`](Self::FIELDS) or
+    /// [`Self::get_field_docs`] rather than the index to lookup the
+    /// corresponding documentation.
     const FIELD_NAMES: &'static [&'static str];
+    /// The name and doc comments of each field or variant of this type,
+    /// indexed by field/variant order.
+    ///
+    /// Unlike [`FIELD_NAMES`](Self::FIELD_NAMES), this is always aligned with
+    /// [`FIELD_DOCS`](Self::FIELD_DOCS). Anonymous fields without a name have
+    /// `None` as their name.
+    ///
+    /// Derived implementations always fill this in. Hand-written ones that
+    /// leave it empty are not found by [`field_docs_opt_const`].
+    const FIELDS: &'static [(Option<&'static str>, Option<&'static str>)] = &[];
+    /// The aliases of each field or variant, indexed by field/variant order.
+    ///
+    /// These are declared with [`alias`](macro@DocumentedFields#7-accept-aliases-and-ignore-case-in-get_field_docs)
+    /// or `#[doc(alias = "...")]`, and are accepted by [`Self::get_field_docs`]
+    /// in addition to [`FIELD_NAMES`](Self::FIELD_NAMES).
+    const FIELD_ALIASES: &'static [&'static [&'static str]] = &[];
+
+    /// Constant internally used by `documented`.
+    #[doc(hidden)]
+    const __DOCUMENTED_CASE_INSENSITIVE: bool = false;
 
     /// Method internally used by `documented`.
     #[doc(hidden)]
     fn __documented_get_index<T: AsRef<str>>(field_name: T) -> Option<usize>;
 
+    /// Method internally used by `documented`.
+    #[doc(hidden)]
+    fn __documented_get_nested_field_docs(
+        _index: usize,
+        _field_path: &str,
+    ) -> Option<Result<&'static str, Error>> {
+        None
+    }
+
     /// Get a field's documentation using its name.
     ///
     /// Note that for structs with anonymous fields (i.e. tuple structs), this
@@ -102,12 +309,27 @@ pub trait DocumentedFieldsOpt {
     /// case, you can either:
     ///
     /// 1. use [`FIELD_DOCS`](Self::FIELD_DOCS) directly instead;
-    /// 2. [set a custom name](macro@DocumentedFields#2-set-a-custom-name-for-a-specific-field-for-get_field_docs-like-so) for the anonymous field.
+    /// 2. [set a custom name](macro@DocumentedFields#2-set-a-custom-name-for-a-specific-field-for-get_field_docs-like-so) for the anonymous field;
+    /// 3. [name anonymous fields by position](macro@DocumentedFields#11-name-anonymous-fields-by-position), e.g. `"0"`.
+    ///
+    /// Fields of [flattened](macro@DocumentedFields#5-flatten-a-fields-own-fields-into-dotted-paths)
+    /// fields can be looked up using a dotted path, e.g. `"server.tls.cert_path"`.
+    /// The same goes for the fields of enum variants, e.g. `"Deploy.env"`.
+    ///
+    /// If the field does not exist, the returned [`Error::NoSuchField`]
+    /// suggests similarly named fields.
     fn get_field_docs<T: AsRef<str>>(field_name: T) -> Result<&'static str, Error> {
         let field_name = field_name.as_ref();
-        let index = Self::__documented_get_index(field_name)
-            .ok_or_else(|| Error::NoSuchField(field_name.into()))?;
-        Self::FIELD_DOCS[index].ok_or_else(|| Error::NoDocComments(field_name.into()))
+        match Self::__documented_get_index(field_name) {
+            Some(index) => Self::FIELD_DOCS[index]
+                .ok_or_else(|| Error::_private_no_doc_comments::<Self>(field_name)),
+            None => get_nested_field_docs::<Self>(
+                field_name,
+                Self::FIELD_NAMES,
+                |name| Self::__documented_get_index(name),
+                Self::__documented_get_nested_field_docs,
+            ),
+        }
     }
 }
 
@@ -122,23 +344,337 @@ pub trait DocumentedFieldsOpt {
 ///
 /// For how to use the derive macro, see [`macro@DocumentedVariants`].
 pub trait DocumentedVariants {
+    /// Variant names, as accepted by [`Self::get_variant_docs_by_name`].
+    ///
+    /// [Skipped](macro@DocumentedVariants#6-leave-out-variants-from-name-lookups)
+    /// variants are not listed.
+    const VARIANT_NAMES: &'static [&'static str] = &[];
+
     /// Get the documentation on this enum variant.
     fn get_variant_docs(&self) -> &'static str;
+
+    /// Method internally used by `documented`.
+    #[doc(hidden)]
+    fn __documented_get_variant_field_docs(
+        &self,
+        _field_name: &str,
+    ) -> Option<Option<&'static str>> {
+        None
+    }
+
+    /// Method internally used by `documented`.
+    #[doc(hidden)]
+    fn __documented_variant_field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Get the documentation on a field of this enum variant.
+    ///
+    /// Unnamed fields (i.e. fields of tuple variants) are named by their
+    /// position, i.e. `"0"`, `"1"`, etc.
+    ///
+    /// Doc comments on these fields are optional, so this method may return
+    /// [`Error::NoDocComments`] even for derives that require docs on variants.
+    ///
+    /// If the field does not exist, the returned [`Error::NoSuchField`]
+    /// suggests similarly named fields of this variant.
+    fn get_variant_field_docs<T: AsRef<str>>(&self, field_name: T) -> Result<&'static str, Error> {
+        let field_name = field_name.as_ref();
+        self.__documented_get_variant_field_docs(field_name)
+            .ok_or_else(|| {
+                Error::_private_no_such_field::<Self>(
+                    field_name,
+                    self.__documented_variant_field_names(),
+                )
+            })?
+            .ok_or_else(|| Error::_private_no_doc_comments::<Self>(field_name))
+    }
+
+    /// Method internally used by `documented`.
+    #[doc(hidden)]
+    fn __documented_get_variant_docs_by_name<T: AsRef<str>>(
+        _variant_name: T,
+    ) -> Option<&'static str> {
+        None
+    }
+
+    /// Get a variant's documentation using its name, without needing an
+    /// instance of the variant.
+    ///
+    /// If the variant does not exist, the returned [`Error::NoSuchField`]
+    /// suggests similarly named variants.
+    fn get_variant_docs_by_name<T: AsRef<str>>(variant_name: T) -> Result<&'static str, Error> {
+        let variant_name = variant_name.as_ref();
+        Self::__documented_get_variant_docs_by_name(variant_name)
+            .ok_or_else(|| Error::_private_no_such_field::<Self>(variant_name, Self::VARIANT_NAMES))
+    }
 }
 
 /// The optional variant of [`DocumentedVariants`].
 pub trait DocumentedVariantsOpt {
+    /// Variant names, as accepted by [`Self::get_variant_docs_by_name`].
+    ///
+    /// [Skipped](macro@DocumentedVariants#6-leave-out-variants-from-name-lookups)
+    /// variants are not listed.
+    const VARIANT_NAMES: &'static [&'static str] = &[];
+
     /// Get the documentation on this enum variant.
     fn get_variant_docs(&self) -> Option<&'static str>;
+
+    /// Method internally used by `documented`.
+    #[doc(hidden)]
+    fn __documented_get_variant_field_docs(
+        &self,
+        _field_name: &str,
+    ) -> Option<Option<&'static str>> {
+        None
+    }
+
+    /// Method internally used by `documented`.
+    #[doc(hidden)]
+    fn __documented_variant_field_names(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Get the documentation on a field of this enum variant.
+    ///
+    /// Unnamed fields (i.e. fields of tuple variants) are named by their
+    /// position, i.e. `"0"`, `"1"`, etc.
+    ///
+    /// Doc comments on these fields are optional, so this method may return
+    /// [`Error::NoDocComments`] even for derives that require docs on variants.
+    ///
+    /// If the field does not exist, the returned [`Error::NoSuchField`]
+    /// suggests similarly named fields of this variant.
+    fn get_variant_field_docs<T: AsRef<str>>(&self, field_name: T) -> Result<&'static str, Error> {
+        let field_name = field_name.as_ref();
+        self.__documented_get_variant_field_docs(field_name)
+            .ok_or_else(|| {
+                Error::_private_no_such_field::<Self>(
+                    field_name,
+                    self.__documented_variant_field_names(),
+                )
+            })?
+            .ok_or_else(|| Error::_private_no_doc_comments::<Self>(field_name))
+    }
+
+    /// Method internally used by `documented`.
+    #[doc(hidden)]
+    fn __documented_get_variant_docs_by_name<T: AsRef<str>>(
+        _variant_name: T,
+    ) -> Option<Option<&'static str>> {
+        None
+    }
+
+    /// Get a variant's documentation using its name, without needing an
+    /// instance of the variant.
+    ///
+    /// If the variant does not exist, the returned [`Error::NoSuchField`]
+    /// suggests similarly named variants.
+    fn get_variant_docs_by_name<T: AsRef<str>>(variant_name: T) -> Result<&'static str, Error> {
+        let variant_name = variant_name.as_ref();
+        Self::__documented_get_variant_docs_by_name(variant_name)
+            .ok_or_else(|| {
+                Error::_private_no_such_field::<Self>(variant_name, Self::VARIANT_NAMES)
+            })?
+            .ok_or_else(|| Error::_private_no_doc_comments::<Self>(variant_name))
+    }
+}
+
+/// Get the documentation of a field (or variant) of `T` by name, in const
+/// context.
+///
+/// Unlike [`DocumentedFields::get_field_docs`], this returns `None` for
+/// unknown names, and does not look into flattened fields or the fields of
+/// variants. Names, aliases and case-insensitivity are honoured the same way.
+///
+/// ```rust
+/// # use documented::{field_docs_const, DocumentedFields};
+/// #[derive(DocumentedFields)]
+/// struct Config {
+///     /// The port to listen on.
+///     port: u16,
+/// }
+///
+/// const HELP: [(&str, Option<&str>); 2] = [
+///     ("port", field_docs_const::<Config>("port")),
+///     ("host", field_docs_const::<Config>("host")),
+/// ];
+///
+/// assert_eq!(HELP, [("port", Some("The port to listen on.")), ("host", None)]);
+/// ```
+pub const fn field_docs_const<T: DocumentedFields + ?Sized>(
+    field_name: &str,
+) -> Option<&'static str> {
+    match const_field_index(
+        T::FIELDS,
+        T::FIELD_ALIASES,
+        T::__DOCUMENTED_CASE_INSENSITIVE,
+        field_name,
+    ) {
+        Some(index) => Some(T::FIELDS[index].1),
+        None => None,
+    }
+}
+
+/// The optional variant of [`field_docs_const`].
+///
+/// Returns `None` both for unknown names and for fields without doc comments.
+pub const fn field_docs_opt_const<T: DocumentedFieldsOpt + ?Sized>(
+    field_name: &str,
+) -> Option<&'static str> {
+    match const_field_index(
+        T::FIELDS,
+        T::FIELD_ALIASES,
+        T::__DOCUMENTED_CASE_INSENSITIVE,
+        field_name,
+    ) {
+        Some(index) => T::FIELDS[index].1,
+        None => None,
+    }
+}
+
+/// Find the index of the field with the given name or alias, in const context.
+const fn const_field_index<D>(
+    fields: &[(Option<&str>, D)],
+    aliases: &[&[&str]],
+    case_insensitive: bool,
+    field_name: &str,
+) -> Option<usize> {
+    const fn eq(a: &str, b: &str, case_insensitive: bool) -> bool {
+        if case_insensitive {
+            const_str_eq_ignore_ascii_case(a, b)
+        } else {
+            const_str_eq(a, b)
+        }
+    }
+
+    let mut i = 0;
+    while i < fields.len() {
+        if let Some(name) = fields[i].0 {
+            if eq(name, field_name, case_insensitive) {
+                return Some(i);
+            }
+        }
+        i += 1;
+    }
+    let mut i = 0;
+    while i < aliases.len() {
+        let mut j = 0;
+        while j < aliases[i].len() {
+            if eq(aliases[i][j], field_name, case_insensitive) {
+                return Some(i);
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Resolve a dotted field path (e.g. `server.tls`) through a flattened field.
+fn get_nested_field_docs<T: ?Sized>(
+    field_path: &str,
+    field_names: &[&'static str],
+    get_index: impl Fn(&str) -> Option<usize>,
+    get_nested: impl Fn(usize, &str) -> Option<Result<&'static str, Error>>,
+) -> Result<&'static str, Error> {
+    let (head, tail) = field_path.split_once('.').unwrap_or((field_path, ""));
+    // only the first segment is looked up at this level, so only it can be
+    // suggested against
+    let index = get_index(head).ok_or_else(|| {
+        Error::_private_no_such_field::<T>(head, field_names).nested_in::<T>(field_path, 0)
+    })?;
+    // the rest of the path is relative to the field, so errors from there are
+    // reported against the full path and `T`
+    let parent_len = head.len() + 1;
+    get_nested(index, tail)
+        .ok_or_else(|| Error::_private_no_such_field::<T>(tail, &[]))
+        .and_then(|res| res)
+        .map_err(|err| err.nested_in::<T>(field_path, parent_len))
 }
 
 /// Errors of `documented`.
-#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Error {
-    /// The requested field does not have doc comments.
-    #[error(r#"The field "{0}" has no doc comments"#)]
-    NoDocComments(String),
-    /// The requested field does not exist.
-    #[error(r#"No field named "{0}" exists"#)]
-    NoSuchField(String),
+    /// The requested field or variant does not have doc comments.
+    NoDocComments(ErrorName),
+    /// The requested field or variant does not exist.
+    ///
+    /// When returned by the lookups of this crate, the name also carries the
+    /// [type that was searched](ErrorName::type_name) and
+    /// [suggestions](ErrorName::suggestions), which are included when
+    /// displayed.
+    ///
+    /// ```rust
+    /// # use documented::{DocumentedFields, Error};
+    /// #[derive(DocumentedFields)]
+    /// struct Config {
+    ///     /// The port to listen on.
+    ///     port: u16,
+    /// }
+    ///
+    /// let err = Config::get_field_docs("prot").unwrap_err();
+    /// assert_eq!(err, Error::NoSuchField("prot".into()));
+    /// assert!(err.to_string().ends_with(r#"; did you mean "port"?"#));
+    /// ```
+    NoSuchField(ErrorName),
+}
+impl Error {
+    /// Constructor internally used by `documented`.
+    #[doc(hidden)]
+    pub fn _private_no_doc_comments<T: ?Sized>(field_name: &str) -> Self {
+        Self::NoDocComments(field_name.into())
+    }
+
+    /// Constructor internally used by `documented`.
+    #[doc(hidden)]
+    pub fn _private_no_such_field<T: ?Sized>(
+        field_name: &str,
+        known_names: &[&'static str],
+    ) -> Self {
+        let name = ErrorName::from(field_name)
+            .with_details::<T>(Suggestions::new(field_name, known_names));
+        Self::NoSuchField(name)
+    }
+
+    /// Report this error as part of a lookup of `field_path` in `T`, where
+    /// `parent_len` bytes of `field_path` precede the name originally looked
+    /// up.
+    fn nested_in<T: ?Sized>(self, field_path: &str, parent_len: usize) -> Self {
+        match self {
+            Self::NoDocComments(name) => {
+                Self::NoDocComments(name.nested_in::<T>(field_path, parent_len))
+            }
+            Self::NoSuchField(name) => {
+                Self::NoSuchField(name.nested_in::<T>(field_path, parent_len))
+            }
+        }
+    }
+}
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NoDocComments(name) => write!(f, r#"The field "{name}" has no doc comments"#),
+            Self::NoSuchField(name) => {
+                write!(f, r#"No field named "{name}" exists"#)?;
+                if let Some(type_name) = name.type_name() {
+                    write!(f, r#" in "{type_name}""#)?;
+                }
+                let parent = name.parent_path();
+                let mut suggestions = name.suggestions().iter().peekable();
+                if let Some(first) = suggestions.next() {
+                    write!(f, r#"; did you mean "{parent}{first}""#)?;
+                    while let Some(next) = suggestions.next() {
+                        let sep = if suggestions.peek().is_some() { "," } else { " or" };
+                        write!(f, r#"{sep} "{parent}{next}""#)?;
+                    }
+                    write!(f, "?")?;
+                }
+                Ok(())
+            }
+        }
+    }
 }
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
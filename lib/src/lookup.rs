@@ -0,0 +1,61 @@
+//! Name lookup backends for the generated code, which depend on the features
+//! of this crate.
+//!
+//! These are declarative macros so that the choice is made when this crate is
+//! compiled, instead of when the procedural macros are.
+
+/// Look up the index associated with a name, using the default backend.
+///
+/// Only meant to be used by the macros of this crate.
+#[cfg(feature = "phf")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _private_lookup_auto {
+    ($key:expr; $($name:tt => $index:tt),* $(,)?) => {
+        $crate::_private_lookup_phf!($key; $($name => $index),*)
+    };
+}
+
+/// Look up the index associated with a name, using the default backend.
+///
+/// Only meant to be used by the macros of this crate.
+#[cfg(not(feature = "phf"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _private_lookup_auto {
+    ($key:expr; $($name:tt => $index:tt),* $(,)?) => {
+        match $key {
+            $($name => Some($index),)*
+            _ => None,
+        }
+    };
+}
+
+/// Look up the index associated with a name, using a perfect hash map.
+///
+/// Only meant to be used by the macros of this crate.
+#[cfg(feature = "phf")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _private_lookup_phf {
+    ($key:expr; $($name:tt => $index:tt),* $(,)?) => {{
+        use $crate::_private_phf_reexport_for_macro as phf;
+
+        static PHF: phf::Map<&'static str, usize> = phf::phf_map! {
+            $($name => $index,)*
+        };
+        PHF.get($key).copied()
+    }};
+}
+
+/// Look up the index associated with a name, using a perfect hash map.
+///
+/// Only meant to be used by the macros of this crate.
+#[cfg(not(feature = "phf"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _private_lookup_phf {
+    ($($tt:tt)*) => {
+        compile_error!("`lookup = \"phf\"` requires the `phf` feature of `documented`")
+    };
+}
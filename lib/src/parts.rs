@@ -0,0 +1,189 @@
+//! Splitting of doc comments into their structural parts.
+//!
+//! The summary and body are computed with `const fn`s so that they can be
+//! provided as associated constants, regardless of whether the docs came from
+//! literals or macros. Sections are parsed lazily at runtime.
+
+use core::iter::FusedIterator;
+
+/// Split docs into the summary (i.e. the first paragraph) and the body
+/// (i.e. everything after it), both with surrounding blank lines removed.
+pub(crate) const fn split_summary(docs: &str) -> (&str, &str) {
+    let bytes = docs.as_bytes();
+
+    let start = skip_blank_lines(bytes, 0);
+    let mut end = start;
+    let mut i = start;
+    while i < bytes.len() {
+        let line_end = find_line_end(bytes, i);
+        let is_first_line = i == start;
+        if is_blank(bytes, i, line_end)
+            || (!is_first_line
+                && (heading_level(bytes, i, line_end) > 0 || is_fence(bytes, i, line_end)))
+        {
+            break;
+        }
+        end = line_end;
+        i = line_end + 1;
+    }
+
+    let body_start = skip_blank_lines(bytes, end);
+    let body_end = trim_end(bytes, body_start, bytes.len());
+    (substr(docs, start, end), substr(docs, body_start, body_end))
+}
+
+/// A section of the documentation, headed by a Markdown heading.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Section {
+    /// The text of the heading, without the leading `#`s.
+    pub heading: &'static str,
+    /// The level of the heading, i.e. the number of leading `#`s.
+    pub level: usize,
+    /// The content under the heading, up to the next heading of the same or
+    /// a higher level, with surrounding blank lines removed.
+    ///
+    /// Note that the content of a section includes its subsections.
+    pub content: &'static str,
+}
+
+/// An iterator over the [`Section`]s of some documentation, in order of
+/// appearance.
+///
+/// Created by [`DocumentedParts::sections`](crate::DocumentedParts::sections).
+#[derive(Clone, Debug)]
+pub struct Sections {
+    docs: &'static str,
+    pos: usize,
+}
+impl Sections {
+    pub(crate) fn new(docs: &'static str) -> Self {
+        Self { docs, pos: 0 }
+    }
+
+    /// Find the next heading outside of code blocks, starting from `pos`.
+    ///
+    /// Returns the start and end of the heading line, and its level.
+    fn find_heading(&self, mut pos: usize, max_level: usize) -> Option<(usize, usize, usize)> {
+        let bytes = self.docs.as_bytes();
+        let mut in_code_block = false;
+        while pos < bytes.len() {
+            let line_end = find_line_end(bytes, pos);
+            if is_fence(bytes, pos, line_end) {
+                in_code_block = !in_code_block;
+            } else if !in_code_block {
+                match heading_level(bytes, pos, line_end) {
+                    0 => {}
+                    level if level <= max_level => return Some((pos, line_end, level)),
+                    _ => {}
+                }
+            }
+            pos = line_end + 1;
+        }
+        None
+    }
+}
+impl Iterator for Sections {
+    type Item = Section;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.docs.as_bytes();
+        let Some((start, line_end, level)) = self.find_heading(self.pos, usize::MAX) else {
+            self.pos = bytes.len();
+            return None;
+        };
+        // continue from the next line, so that subsections are also yielded
+        self.pos = (line_end + 1).min(bytes.len());
+
+        let heading = substr(self.docs, start + level, line_end).trim();
+        let next_start = self
+            .find_heading(self.pos, level)
+            .map_or(bytes.len(), |(next_start, _, _)| next_start);
+        let content_start = skip_blank_lines(bytes, self.pos).min(next_start);
+        let content_end = trim_end(bytes, content_start, next_start);
+        let content = substr(self.docs, content_start, content_end);
+
+        Some(Section { heading, level, content })
+    }
+}
+impl FusedIterator for Sections {}
+
+/// The number of `#`s if the line is a Markdown heading, or `0` otherwise.
+const fn heading_level(bytes: &[u8], start: usize, end: usize) -> usize {
+    let mut i = start;
+    while i < end && bytes[i] == b'#' {
+        i += 1;
+    }
+    let level = i - start;
+    if level > 6 || (i < end && bytes[i] != b' ' && bytes[i] != b'\t') {
+        0
+    } else {
+        level
+    }
+}
+
+/// Whether the line opens or closes a fenced code block.
+const fn is_fence(bytes: &[u8], start: usize, end: usize) -> bool {
+    let mut i = start;
+    while i < end && bytes[i] == b' ' {
+        i += 1;
+    }
+    i + 3 <= end
+        && ((bytes[i] == b'`' && bytes[i + 1] == b'`' && bytes[i + 2] == b'`')
+            || (bytes[i] == b'~' && bytes[i + 1] == b'~' && bytes[i + 2] == b'~'))
+}
+
+/// Whether the line only contains whitespace.
+const fn is_blank(bytes: &[u8], start: usize, end: usize) -> bool {
+    let mut i = start;
+    while i < end {
+        if !bytes[i].is_ascii_whitespace() {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Find the index of the `\n` ending the line starting at `start`, or the
+/// length of the input if this is the last line.
+const fn find_line_end(bytes: &[u8], start: usize) -> usize {
+    let mut i = start;
+    while i < bytes.len() && bytes[i] != b'\n' {
+        i += 1;
+    }
+    i
+}
+
+/// Find the start of the first non-blank line at or after `start`.
+const fn skip_blank_lines(bytes: &[u8], start: usize) -> usize {
+    let mut i = start;
+    while i < bytes.len() {
+        let line_end = find_line_end(bytes, i);
+        if !is_blank(bytes, i, line_end) {
+            return i;
+        }
+        i = line_end + 1;
+    }
+    bytes.len()
+}
+
+/// Find the end of `bytes[start..end]` with trailing whitespace removed.
+const fn trim_end(bytes: &[u8], start: usize, end: usize) -> usize {
+    let mut end = end;
+    while end > start && bytes[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    end
+}
+
+/// `&s[start..end]`, but usable in `const` contexts.
+///
+/// Both indices must lie on ASCII characters or the ends of the input.
+const fn substr(s: &str, start: usize, end: usize) -> &str {
+    let (_, tail) = s.as_bytes().split_at(start);
+    let (mid, _) = tail.split_at(end - start);
+    match core::str::from_utf8(mid) {
+        Ok(mid) => mid,
+        Err(_) => panic!("substring does not lie on char boundaries"),
+    }
+}
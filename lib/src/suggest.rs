@@ -0,0 +1,127 @@
+//! "Did you mean" suggestions for names that do not exist.
+//!
+//! Everything here works without allocating, so that suggestions are also
+//! available without `std`.
+
+use core::{fmt, iter::FusedIterator};
+
+/// The maximum number of suggestions kept.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Names of at least this many chars are never compared, so that the edit
+/// distance can be computed with a fixed-size buffer.
+const MAX_NAME_LEN: usize = 64;
+
+/// The known names closest to a name that does not exist, best match first.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Suggestions([Option<&'static str>; MAX_SUGGESTIONS]);
+impl Suggestions {
+    /// Pick the names from `candidates` that are likely to be typos of `name`.
+    ///
+    /// A candidate qualifies if its edit distance to `name` is non-zero and at
+    /// most a third of the length of `name`.
+    pub(crate) fn new(name: &str, candidates: &[&'static str]) -> Self {
+        let threshold = name.chars().count().max(3) / 3;
+
+        let mut best = [None::<(usize, &'static str)>; MAX_SUGGESTIONS];
+        for &candidate in candidates {
+            let Some(distance) = edit_distance(name, candidate) else {
+                continue;
+            };
+            if distance == 0 || distance > threshold {
+                continue;
+            }
+            // insert while keeping ties in their original order
+            let Some(pos) = best
+                .iter()
+                .position(|slot| slot.map_or(true, |(d, _)| distance < d))
+            else {
+                continue;
+            };
+            best[pos..].rotate_right(1);
+            best[pos] = Some((distance, candidate));
+        }
+
+        Self(best.map(|slot| slot.map(|(_, candidate)| candidate)))
+    }
+
+    /// Iterate over the suggestions, best match first.
+    pub fn iter(&self) -> SuggestionsIter<'_> {
+        SuggestionsIter(self.0.iter())
+    }
+
+    /// Whether there are no suggestions.
+    pub fn is_empty(&self) -> bool {
+        self.0[0].is_none()
+    }
+}
+impl fmt::Debug for Suggestions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl<'a> IntoIterator for &'a Suggestions {
+    type Item = &'static str;
+    type IntoIter = SuggestionsIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over [`Suggestions`].
+#[derive(Clone, Debug)]
+pub struct SuggestionsIter<'a>(core::slice::Iter<'a, Option<&'static str>>);
+impl Iterator for SuggestionsIter<'_> {
+    type Item = &'static str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().copied().flatten()
+    }
+}
+impl FusedIterator for SuggestionsIter<'_> {}
+
+/// The edit distance between two strings, counted in chars.
+///
+/// This is the optimal string alignment distance, i.e. the Levenshtein distance
+/// that also counts swapping two adjacent chars as a single edit.
+///
+/// Returns `None` if either string is too long to be compared.
+fn edit_distance(a: &str, b: &str) -> Option<usize> {
+    let mut b_chars = ['\0'; MAX_NAME_LEN];
+    let mut b_len = 0;
+    for c in b.chars() {
+        *b_chars.get_mut(b_len)? = c;
+        b_len += 1;
+    }
+    let b_chars = &b_chars[..b_len];
+    if a.chars().count() >= MAX_NAME_LEN || b_len >= MAX_NAME_LEN {
+        return None;
+    }
+
+    // the distances between the prefixes of `a` processed so far and each
+    // prefix of `b`, for the last two processed prefixes and the current one
+    let mut prev_row = [0; MAX_NAME_LEN];
+    let mut row = [0; MAX_NAME_LEN];
+    for (j, cell) in row.iter_mut().enumerate().take(b_len + 1) {
+        *cell = j;
+    }
+    let mut prev_a_char = None;
+    for (i, a_char) in a.chars().enumerate() {
+        let mut next_row = [0; MAX_NAME_LEN];
+        next_row[0] = i + 1;
+        for (j, &b_char) in b_chars.iter().enumerate() {
+            let mut distance = (row[j + 1] + 1)
+                .min(next_row[j] + 1)
+                .min(row[j] + usize::from(a_char != b_char));
+            if j > 0 && prev_a_char == Some(b_char) && b_chars[j - 1] == a_char {
+                distance = distance.min(prev_row[j - 1] + 1);
+            }
+            next_row[j + 1] = distance;
+        }
+        prev_row = row;
+        row = next_row;
+        prev_a_char = Some(a_char);
+    }
+    Some(row[b_len])
+}